@@ -6,8 +6,9 @@ use std::error::Error;
 use std::time::{SystemTime, Duration};
 use std::{thread, fmt};
 use crate::Result;
+use crate::methods::resources::ResourceCache;
 
-const BASE_URL: &'static str = "https://api.hypixel.net/skyblock/";
+const BASE_URL: &str = "https://api.hypixel.net/";
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
@@ -39,7 +40,7 @@ fn hypixel_api_window_size() -> u64 { 60 }
 fn hypixel_api_window_limit() -> usize { 120 }
 
 impl<'a> Key<'a> {
-	pub fn new(key: &'a str, window_limit: usize, window_size: u64) -> Key {
+	pub fn new(key: &'a str, window_limit: usize, window_size: u64) -> Key<'a> {
 		Key {
 			key,
 			window: SystemTime::now(),
@@ -74,16 +75,18 @@ impl<'a> Key<'a> {
 
 pub struct SkyblockApi<'a> {
 	keys: Vec<Key<'a>>,
+	pub(crate) resources: ResourceCache,
 }
 
 impl<'a> SkyblockApi<'a> {
-	pub fn pooled(keys: Vec<&str>) -> SkyblockApi {
+	pub fn pooled(keys: Vec<&str>) -> SkyblockApi<'_> {
 		SkyblockApi {
 			keys: keys.into_iter().map(|k| Key::new(k, 120, 60)).collect(),
+			resources: ResourceCache::default(),
 		}
 	}
 
-	pub fn singleton(key: &str) -> SkyblockApi {
+	pub fn singleton(key: &str) -> SkyblockApi<'_> {
 		Self::pooled(vec![key])
 	}
 
@@ -99,7 +102,19 @@ impl<'a> SkyblockApi<'a> {
 		}
 	}
 
+	/// Calls an endpoint under `skyblock/`.
 	pub async fn get<T>(&mut self, path: &str, params: Vec<(&str, String)>) -> Result<T> where
+		T: for<'de> Deserialize<'de> {
+		self.request(&format!("skyblock/{}", path), params).await
+	}
+
+	/// Calls an endpoint under `resources/skyblock/`, which serves static game data.
+	pub async fn get_resource<T>(&mut self, path: &str, params: Vec<(&str, String)>) -> Result<T> where
+		T: for<'de> Deserialize<'de> {
+		self.request(&format!("resources/skyblock/{}", path), params).await
+	}
+
+	async fn request<T>(&mut self, path: &str, params: Vec<(&str, String)>) -> Result<T> where
 		T: for<'de> Deserialize<'de> {
 		let uri: Uri = format!("{}{}?key={}{}", BASE_URL, path, self.get_key_sync(), params.iter()
 			.map(|(k, v)| {
@@ -136,13 +151,13 @@ pub(crate) enum ApiBody<T> {
 	Ok(T),
 }
 
-impl<T> Into<Result<T>> for ApiBody<T> {
-	fn into(self) -> Result<T> {
-		match self {
-			Self::Ok(i) => {
+impl<T> From<ApiBody<T>> for Result<T> {
+	fn from(body: ApiBody<T>) -> Result<T> {
+		match body {
+			ApiBody::Ok(i) => {
 				Ok(i)
 			}
-			Self::Error { cause } => {
+			ApiBody::Error { cause } => {
 				Err(Box::new(cause))
 			}
 		}
@@ -12,9 +12,15 @@ extern crate futures;
 extern crate hyper;
 #[cfg(feature = "client")]
 extern crate hyper_tls;
+#[cfg(feature = "watch")]
+extern crate tokio;
 
+#[cfg(feature = "chrono")]
+extern crate chrono;
 #[cfg(feature = "nbt")]
 extern crate nbt;
+#[cfg(feature = "regex")]
+extern crate regex;
 
 #[cfg(feature = "client")]
 pub mod client;
@@ -1,5 +1,15 @@
-use crate::{SkyblockApi, Result, Auction, GlobalAuctions};
+use crate::{SkyblockApi, Result, Auction, GlobalAuctions, PartialAuction, SearchedAuctions};
 use crate::client::ApiBody;
+use crate::objects::auction::index::{AuctionIndex, AuctionIndexBuilder};
+use crate::objects::auction::sync::{AuctionDiff, AuctionSync};
+#[cfg(feature = "watch")]
+use crate::objects::auction::watch::Watchlist;
+#[cfg(feature = "watch")]
+use std::sync::Arc;
+#[cfg(feature = "watch")]
+use std::time::{Duration, SystemTime};
+#[cfg(feature = "watch")]
+use tokio::sync::Mutex;
 
 impl<'a> SkyblockApi<'a> {
 	/// Helper function, allows the user to pass a function tio the API
@@ -24,9 +34,90 @@ impl<'a> SkyblockApi<'a> {
 		Ok(())
 	}
 
+	/// Scans every page of the auction house into a lowest BIN index.
+	/// The index is only handed back once the scan completes, so an existing index
+	/// can keep serving lookups until it is swapped out with `AuctionIndex::update`.
+	/// If the auction house updates partway through, the scan starts over so the index
+	/// never mixes pages from two snapshots.
+	pub async fn index_active_auctions(&mut self) -> Result<AuctionIndex> {
+		'scan: loop {
+			let first = self.get_auctions_page(0).await?;
+			let mut builder = AuctionIndexBuilder::new(first.last_update);
+
+			for auction in &first.auctions {
+				builder.push(auction);
+			}
+
+			for i in 1..first.total_pages {
+				let page = self.get_auctions_page(i).await?;
+
+				if page.last_update != first.last_update {
+					continue 'scan;
+				}
+
+				for auction in &page.auctions {
+					builder.push(auction);
+				}
+			}
+
+			return Ok(builder.finish());
+		}
+	}
+
+	/// Polls the first page of auctions and, only if its `last_update` has advanced past
+	/// the snapshot held by `sync`, rescans the auction house and returns the changes.
+	/// Returns `None` if the snapshot is still current.
+	pub async fn sync_active_auctions(&mut self, sync: &mut AuctionSync) -> Result<Option<AuctionDiff>> {
+		let first = self.get_auctions_page(0).await?;
+
+		if !sync.is_stale(first.last_update) {
+			return Ok(None);
+		}
+
+		let last_update = first.last_update;
+		let mut auctions = first.auctions;
+
+		for i in 1..first.total_pages {
+			auctions.extend(self.get_auctions_page(i).await?.auctions);
+		}
+
+		Ok(Some(sync.apply(last_update, auctions)))
+	}
+
 	/// Returns a particular page of auctions to the caller.
 	pub async fn get_auctions_page(&mut self, page: usize) -> Result<GlobalAuctions> {
 		let body: ApiBody<GlobalAuctions> = self.get("auctions", vec![("page", format!("{}", page))]).await?;
 		body.into()
 	}
+
+	/// Fetches a single auction by its UUID, including ended auctions which haven't been claimed.
+	pub async fn get_auction(&mut self, uuid: &PartialAuction) -> Result<Option<Auction>> {
+		let body: ApiBody<SearchedAuctions> = self.get("auction", vec![("uuid", uuid.0.clone())]).await?;
+		let res: Result<_> = body.into();
+
+		res.map(|searched| searched.auctions.into_iter().next())
+	}
+
+	/// Keeps the watchlist's subscribers up to date with the auction house,
+	/// polling for changes every `interval`. This only returns if a request fails.
+	/// The watchlist is only locked while processing changes, so watches can be added
+	/// and removed through it while this runs.
+	#[cfg(feature = "watch")]
+	pub async fn watch_active_auctions(&mut self, watchlist: Arc<Mutex<Watchlist>>, interval: Duration) -> Result<()> {
+		let mut sync = AuctionSync::new();
+
+		loop {
+			if let Some(diff) = self.sync_active_auctions(&mut sync).await? {
+				watchlist.lock().await.process(&diff, &sync, SystemTime::now());
+			}
+
+			let unclaimed: Vec<PartialAuction> = watchlist.lock().await.unclaimed().cloned().collect();
+			for uuid in unclaimed {
+				let auction = self.get_auction(&uuid).await?;
+				watchlist.lock().await.process_claim(&uuid, auction.as_ref());
+			}
+
+			tokio::time::delay_for(interval).await;
+		}
+	}
 }
@@ -0,0 +1,18 @@
+use crate::{SkyblockApi, Result, BingoCard, BingoPlayer};
+use crate::client::ApiBody;
+
+impl<'a> SkyblockApi<'a> {
+	/// Fetch a player's completed goals and points across every bingo event.
+	pub async fn get_bingo(&mut self, uuid: &str) -> Result<BingoPlayer> {
+		let body: ApiBody<BingoPlayer> = self.get("bingo", vec![("uuid", uuid.to_owned())]).await?;
+
+		body.into()
+	}
+
+	/// Fetch the goals of the current bingo event.
+	pub async fn get_bingo_card(&mut self) -> Result<BingoCard> {
+		let body: ApiBody<BingoCard> = self.get_resource("bingo", vec![]).await?;
+
+		body.into()
+	}
+}
@@ -0,0 +1,11 @@
+use crate::{SkyblockApi, Result, ElectionResource};
+use crate::client::ApiBody;
+
+impl<'a> SkyblockApi<'a> {
+	/// Fetch the current mayor, their minister and any election being voted on.
+	pub async fn get_election(&mut self) -> Result<ElectionResource> {
+		let body: ApiBody<ElectionResource> = self.get_resource("election", vec![]).await?;
+
+		body.into()
+	}
+}
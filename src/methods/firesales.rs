@@ -0,0 +1,11 @@
+use crate::{SkyblockApi, Result, FireSales};
+use crate::client::ApiBody;
+
+impl<'a> SkyblockApi<'a> {
+	/// Fetch the upcoming and active fire sales.
+	pub async fn get_firesales(&mut self) -> Result<FireSales> {
+		let body: ApiBody<FireSales> = self.get("firesales", vec![]).await?;
+
+		body.into()
+	}
+}
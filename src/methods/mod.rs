@@ -1,2 +1,8 @@
 pub mod auction;
-pub mod bazaar;
\ No newline at end of file
+pub mod bazaar;
+pub mod bingo;
+pub mod election;
+pub mod firesales;
+pub mod news;
+pub mod profile;
+pub mod resources;
\ No newline at end of file
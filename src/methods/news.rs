@@ -0,0 +1,11 @@
+use crate::{SkyblockApi, Result, News};
+use crate::client::ApiBody;
+
+impl<'a> SkyblockApi<'a> {
+	/// Fetch the latest SkyBlock news.
+	pub async fn get_news(&mut self) -> Result<News> {
+		let body: ApiBody<News> = self.get("news", vec![]).await?;
+
+		body.into()
+	}
+}
@@ -0,0 +1,50 @@
+use crate::{SkyblockApi, Result, Garden, Museum, Profile, ProfileId};
+use crate::client::ApiBody;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ProfileWrapper {
+	pub profile: Profile
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ProfilesWrapper {
+	pub profiles: Option<Vec<Profile>>
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct GardenWrapper {
+	pub garden: Garden
+}
+
+impl<'a> SkyblockApi<'a> {
+	/// Fetch a profile and the progress of each of its members.
+	pub async fn get_profile(&mut self, profile: &ProfileId) -> Result<Profile> {
+		let body: ApiBody<ProfileWrapper> = self.get("profile", vec![("profile", profile.0.clone())]).await?;
+		let res: Result<_> = body.into();
+
+		res.map(|wrapped| wrapped.profile)
+	}
+
+	/// Fetch every profile of a player, empty if they've never played SkyBlock.
+	pub async fn get_profiles(&mut self, uuid: &str) -> Result<Vec<Profile>> {
+		let body: ApiBody<ProfilesWrapper> = self.get("profiles", vec![("uuid", uuid.to_owned())]).await?;
+		let res: Result<_> = body.into();
+
+		res.map(|wrapped| wrapped.profiles.unwrap_or_default())
+	}
+
+	/// Fetch the museums of every member of a profile.
+	pub async fn get_museum(&mut self, profile: &ProfileId) -> Result<Museum> {
+		let body: ApiBody<Museum> = self.get("museum", vec![("profile", profile.0.clone())]).await?;
+
+		body.into()
+	}
+
+	/// Fetch the garden of a profile.
+	pub async fn get_garden(&mut self, profile: &ProfileId) -> Result<Garden> {
+		let body: ApiBody<GardenWrapper> = self.get("garden", vec![("profile", profile.0.clone())]).await?;
+		let res: Result<_> = body.into();
+
+		res.map(|wrapped| wrapped.garden)
+	}
+}
@@ -0,0 +1,93 @@
+use crate::{SkyblockApi, Result, ItemsResource, CollectionsResource, SkillsResource};
+use crate::client::ApiBody;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long fetched resources are reused for by default; they only change with game updates.
+const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug)]
+struct Cached<T> {
+	fetched: Instant,
+	value: Arc<T>,
+}
+
+/// The resources fetched so far, shared between calls until they expire.
+#[derive(Debug)]
+pub(crate) struct ResourceCache {
+	ttl: Duration,
+	items: Option<Cached<ItemsResource>>,
+	collections: Option<Cached<CollectionsResource>>,
+	skills: Option<Cached<SkillsResource>>,
+}
+
+impl Default for ResourceCache {
+	fn default() -> Self {
+		ResourceCache {
+			ttl: DEFAULT_TTL,
+			items: None,
+			collections: None,
+			skills: None,
+		}
+	}
+}
+
+fn fresh<T>(cached: &Option<Cached<T>>, ttl: Duration) -> Option<Arc<T>> {
+	cached.as_ref()
+		.filter(|cached| cached.fetched.elapsed() < ttl)
+		.map(|cached| cached.value.clone())
+}
+
+impl<'a> SkyblockApi<'a> {
+	/// Sets how long fetched resources are reused before being fetched again, an hour by default.
+	pub fn set_resource_ttl(&mut self, ttl: Duration) {
+		self.resources.ttl = ttl;
+	}
+
+	/// Drops every cached resource, so the next call fetches them again.
+	pub fn clear_resource_cache(&mut self) {
+		self.resources = ResourceCache {
+			ttl: self.resources.ttl,
+			..ResourceCache::default()
+		};
+	}
+
+	/// Fetch every item in the game, keyed by ID.
+	pub async fn get_items(&mut self) -> Result<Arc<ItemsResource>> {
+		if let Some(items) = fresh(&self.resources.items, self.resources.ttl) {
+			return Ok(items);
+		}
+
+		let body: ApiBody<ItemsResource> = self.get_resource("items", vec![]).await?;
+		let items = Arc::new(Result::from(body)?);
+		self.resources.items = Some(Cached { fetched: Instant::now(), value: items.clone() });
+
+		Ok(items)
+	}
+
+	/// Fetch the collections and the amounts required for each of their tiers.
+	pub async fn get_collections(&mut self) -> Result<Arc<CollectionsResource>> {
+		if let Some(collections) = fresh(&self.resources.collections, self.resources.ttl) {
+			return Ok(collections);
+		}
+
+		let body: ApiBody<CollectionsResource> = self.get_resource("collections", vec![]).await?;
+		let collections = Arc::new(Result::from(body)?);
+		self.resources.collections = Some(Cached { fetched: Instant::now(), value: collections.clone() });
+
+		Ok(collections)
+	}
+
+	/// Fetch the skills and the XP required for each of their levels.
+	pub async fn get_skills(&mut self) -> Result<Arc<SkillsResource>> {
+		if let Some(skills) = fresh(&self.resources.skills, self.resources.ttl) {
+			return Ok(skills);
+		}
+
+		let body: ApiBody<SkillsResource> = self.get_resource("skills", vec![]).await?;
+		let skills = Arc::new(Result::from(body)?);
+		self.resources.skills = Some(Cached { fetched: Instant::now(), value: skills.clone() });
+
+		Ok(skills)
+	}
+}
@@ -1,13 +1,31 @@
 pub mod auction;
 pub mod bazaar;
+pub mod bingo;
+pub mod election;
+pub mod firesales;
+pub mod garden;
+pub mod museum;
 pub mod profile;
 #[cfg(feature = "nbt")]
 pub mod nbt;
 pub mod items;
+pub mod news;
+pub mod pricing;
+pub mod recipes;
+pub mod resources;
 
 pub use auction::*;
 pub use bazaar::*;
+pub use bingo::*;
+pub use election::*;
+pub use firesales::*;
+pub use garden::*;
+pub use museum::*;
 pub use profile::*;
 #[cfg(feature = "nbt")]
 pub use self::nbt::*;
 pub use items::*;
+pub use news::*;
+pub use pricing::*;
+pub use recipes::*;
+pub use resources::*;
@@ -1,13 +1,24 @@
 use crate::objects::profile::PartialProfile;
 use crate::objects::items::Item;
 
+pub mod bids;
+pub mod fees;
+pub mod filter;
+pub mod index;
+pub mod search;
+pub mod sniper;
+pub mod sync;
+pub mod time;
+#[cfg(feature = "watch")]
+pub mod watch;
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct Claim {
 	pub claimed: bool,
 	// pub claimed_bidders: Vec<String>, // TODO
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 #[serde(transparent)]
 pub struct PartialAuction(pub String);
 
@@ -24,7 +35,11 @@ pub struct Auction {
 	pub start: i64,
 	/// Unix time (in milliseconds) of when the auction is currently projected to end.
 	/// This is only an estimate as it does not account for last-2-minute bids extending the end time.
+	/// `projected_end` corrects for the bids seen so far.
 	pub end: i64,
+	/// Whether the auction is a "Buy It Now" listing, sold outright for the starting bid.
+	#[serde(default)]
+	pub bin: bool,
 	/// Fields pertaining to the item itself.
 	#[serde(flatten)]
 	pub item: Item,
@@ -76,13 +91,12 @@ pub struct GlobalAuctions {
 	pub total_auctions: usize,
 	/// The timestamp of the last update that has occurred in the dataset
 	#[serde(rename = "lastUpdated")]
-	pub last_update: u64,
+	pub last_update: i64,
 	/// The list of auctions retrieved
 	pub auctions: Vec<Auction>,
 }
 
 /// An auction that had been searched by UUID.
-#[cfg(test)]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct SearchedAuctions {
 	pub auctions: Vec<Auction>,
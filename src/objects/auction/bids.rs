@@ -0,0 +1,90 @@
+use crate::objects::auction::{Auction, Bid, Bids};
+use crate::objects::auction::time::from_millis;
+use crate::objects::profile::PartialProfile;
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Bids placed this close to the end of an auction push the end back.
+const EXTENSION_WINDOW: i64 = 2 * 60 * 1000;
+
+impl Bids {
+	pub fn count(&self) -> usize {
+		self.bids.len()
+	}
+
+	/// The distinct profile-members that have bid on the auction.
+	pub fn bidders(&self) -> HashSet<&PartialProfile> {
+		self.bids.iter().map(|bid| &bid.bidder).collect()
+	}
+
+	pub fn unique_bidders(&self) -> usize {
+		self.bidders().len()
+	}
+
+	/// The most recently placed bid.
+	pub fn last_bid(&self) -> Option<&Bid> {
+		self.bids.iter().max_by_key(|bid| bid.timestamp)
+	}
+
+	pub fn last_bid_time(&self) -> Option<SystemTime> {
+		self.last_bid().map(Bid::time)
+	}
+
+	/// The bid currently winning the auction.
+	pub fn winning_bid(&self) -> Option<&Bid> {
+		self.bids.iter().max_by_key(|bid| bid.amount)
+	}
+
+	/// Whether the profile-member holds the winning bid.
+	pub fn is_winning(&self, profile: &PartialProfile) -> bool {
+		matches!(self.winning_bid(), Some(bid) if &bid.bidder == profile)
+	}
+
+	/// The rate of bidding in bids per hour, over the `window` leading up to `now`.
+	/// The window is cut short at the Unix epoch, and is zero if it's empty.
+	pub fn velocity(&self, window: Duration, now: SystemTime) -> f64 {
+		let since = now.checked_sub(window).unwrap_or(UNIX_EPOCH);
+		let hours = now.duration_since(since).unwrap_or_default().as_secs_f64() / 3600.0;
+		if hours <= 0.0 {
+			return 0.0;
+		}
+
+		let bids = self.bids.iter()
+			.filter(|bid| (since..=now).contains(&bid.time()))
+			.count();
+
+		bids as f64 / hours
+	}
+}
+
+impl Auction {
+	/// The bids which landed in the last two minutes of the auction and pushed its end back.
+	pub fn extensions(&self) -> Vec<&Bid> {
+		let mut bids: Vec<&Bid> = self.bids.bids.iter().collect();
+		bids.sort_by_key(|bid| bid.timestamp);
+
+		let mut end = self.end;
+		bids.into_iter()
+			.filter(|bid| {
+				let extends = bid.timestamp > end - EXTENSION_WINDOW && bid.timestamp <= end;
+				if extends {
+					end = bid.timestamp + EXTENSION_WINDOW;
+				}
+
+				extends
+			})
+			.collect()
+	}
+
+	/// Unix time (in milliseconds) of when the auction is projected to end,
+	/// corrected for bids in the last two minutes extending the end time.
+	pub fn projected_end(&self) -> i64 {
+		self.extensions()
+			.last()
+			.map_or(self.end, |bid| bid.timestamp + EXTENSION_WINDOW)
+	}
+
+	pub fn projected_end_time(&self) -> SystemTime {
+		from_millis(self.projected_end())
+	}
+}
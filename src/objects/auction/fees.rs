@@ -0,0 +1,175 @@
+use crate::objects::auction::Auction;
+use std::time::Duration;
+
+/// A band of the listing fee, charged on listings priced at `from` coins or more.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct FeeTier {
+	/// The least price this band applies to
+	pub from: i64,
+	/// The fraction of the price charged
+	pub rate: f64,
+}
+
+/// The fee charged for listing an auction that runs for up to `hours` hours.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DurationFee {
+	pub hours: u64,
+	pub fee: i64,
+}
+
+/// A proposed or existing listing on the auction house.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Listing {
+	/// Whether the listing is a BIN, which always sells at `price`
+	pub bin: bool,
+	/// The BIN price or the starting bid, which the listing fee is charged on
+	pub price: i64,
+	/// The price the item sells for; the highest bid of an auction, or `price` for a BIN
+	pub sale_price: i64,
+	/// How long the listing runs for
+	pub duration: Duration,
+}
+
+impl Listing {
+	pub fn bin(price: i64, duration: Duration) -> Listing {
+		Listing {
+			bin: true,
+			price,
+			sale_price: price,
+			duration,
+		}
+	}
+
+	pub fn auction(starting_bid: i64, sale_price: i64, duration: Duration) -> Listing {
+		Listing {
+			bin: false,
+			price: starting_bid,
+			sale_price,
+			duration,
+		}
+	}
+}
+
+/// The coins made from a sale, broken down by fee.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Proceeds {
+	/// The fee paid up front on the listed price
+	pub listing_fee: i64,
+	/// The fee paid up front for the length of the listing
+	pub duration_fee: i64,
+	/// The tax taken when the coins from the sale are claimed
+	pub claim_tax: i64,
+	/// The coins left from the sale once every fee is paid
+	pub net: i64,
+}
+
+impl Proceeds {
+	pub fn fees(&self) -> i64 {
+		self.listing_fee + self.duration_fee + self.claim_tax
+	}
+}
+
+/// Hypixel's auction house fees.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct FeeSchedule {
+	/// Bands of the listing fee for BINs, ordered by price
+	pub listing_tiers: Vec<FeeTier>,
+	/// Bands of the listing fee for auctions, charged on the starting bid and ordered by price
+	pub auction_tiers: Vec<FeeTier>,
+	/// Fees for each selectable duration, ordered by length
+	pub duration_fees: Vec<DurationFee>,
+	/// The fraction of a sale taken when claiming it
+	pub claim_tax_rate: f64,
+	/// Sales above this many coins are subject to the claim tax
+	pub claim_tax_threshold: i64,
+}
+
+impl Default for FeeSchedule {
+	fn default() -> Self {
+		FeeSchedule {
+			listing_tiers: vec![
+				FeeTier { from: 0, rate: 0.01 },
+				FeeTier { from: 10_000_000, rate: 0.02 },
+				FeeTier { from: 100_000_000, rate: 0.025 },
+			],
+			auction_tiers: vec![
+				FeeTier { from: 0, rate: 0.05 },
+			],
+			duration_fees: vec![
+				DurationFee { hours: 1, fee: 20 },
+				DurationFee { hours: 6, fee: 45 },
+				DurationFee { hours: 12, fee: 100 },
+				DurationFee { hours: 24, fee: 350 },
+				DurationFee { hours: 48, fee: 1200 },
+			],
+			claim_tax_rate: 0.01,
+			claim_tax_threshold: 1_000_000,
+		}
+	}
+}
+
+impl FeeSchedule {
+	/// The fee for listing an item at `price`, charged at the rate of the highest band it reaches.
+	/// BINs and auctions each have their own bands.
+	pub fn listing_fee(&self, bin: bool, price: i64) -> i64 {
+		let tiers = if bin { &self.listing_tiers } else { &self.auction_tiers };
+		let rate = tiers.iter()
+			.rev()
+			.find(|tier| price >= tier.from)
+			.map_or(0.0, |tier| tier.rate);
+
+		(price as f64 * rate) as i64
+	}
+
+	/// The fee for the shortest selectable duration covering `duration`.
+	/// Durations beyond the longest option are charged as the longest option.
+	pub fn duration_fee(&self, duration: Duration) -> i64 {
+		// Rounded up to whole hours.
+		let hours = duration.as_secs().saturating_add(3599) / 3600;
+
+		self.duration_fees.iter()
+			.find(|option| option.hours >= hours)
+			.or_else(|| self.duration_fees.last())
+			.map_or(0, |option| option.fee)
+	}
+
+	/// The tax taken when claiming the coins from a sale of `sale_price`.
+	pub fn claim_tax(&self, sale_price: i64) -> i64 {
+		if sale_price > self.claim_tax_threshold {
+			(sale_price as f64 * self.claim_tax_rate) as i64
+		} else {
+			0
+		}
+	}
+
+	pub fn proceeds(&self, listing: &Listing) -> Proceeds {
+		let listing_fee = self.listing_fee(listing.bin, listing.price);
+		let duration_fee = self.duration_fee(listing.duration);
+		let claim_tax = self.claim_tax(listing.sale_price);
+
+		Proceeds {
+			listing_fee,
+			duration_fee,
+			claim_tax,
+			net: listing.sale_price - listing_fee - duration_fee - claim_tax,
+		}
+	}
+}
+
+impl Auction {
+	/// Describes the auction as a listing, selling at its current highest bid (or starting bid if there are none).
+	pub fn listing(&self) -> Listing {
+		let duration = self.end_time().duration_since(self.start_time()).unwrap_or_default();
+
+		if self.bin {
+			Listing::bin(self.bids.starting, duration)
+		} else {
+			Listing::auction(self.bids.starting, self.bids.highest.max(self.bids.starting), duration)
+		}
+	}
+
+	/// The coins the auctioneer makes if the auction sells as it stands.
+	pub fn proceeds(&self, schedule: &FeeSchedule) -> Proceeds {
+		schedule.proceeds(&self.listing())
+	}
+}
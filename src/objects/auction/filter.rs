@@ -0,0 +1,189 @@
+use crate::Result;
+use crate::objects::auction::Auction;
+use crate::objects::items::{Category, Rarity};
+use crate::objects::profile::PartialProfile;
+#[cfg(feature = "regex")]
+use regex::Regex;
+use std::ops::Not;
+use std::time::{Duration, SystemTime};
+
+/// A composable predicate over auctions.
+/// Filters are built from the constructors below and combined with `and`, `or` and `!`.
+#[derive(Debug, Clone)]
+pub enum AuctionFilter {
+	/// Matches every auction
+	Any,
+	/// The item's name contains the text, ignoring case
+	NameContains(String),
+	/// The item's `extra` field contains the text, ignoring case
+	ExtraContains(String),
+	/// The item's name matches the expression
+	#[cfg(feature = "regex")]
+	NameMatches(Regex),
+	/// The item's `extra` field matches the expression
+	#[cfg(feature = "regex")]
+	ExtraMatches(Regex),
+	/// The item is listed under the category
+	Category(Category),
+	/// The item's tier is within the inclusive range of ranks, see `Rarity::rank`
+	Tier(Rarity, Rarity),
+	/// The auction's current price (highest bid, or starting bid if there are none) is within the inclusive range
+	Price(i64, i64),
+	/// The auction is a BIN listing
+	Bin,
+	/// The auction is projected to end within the duration
+	EndingWithin(Duration),
+	/// The item carries the enchantment at the level or higher
+	#[cfg(feature = "nbt")]
+	Enchantment(String, i32),
+	/// The auction was listed by the profile
+	Auctioneer(PartialProfile),
+	/// Every filter matches
+	And(Vec<AuctionFilter>),
+	/// Any filter matches
+	Or(Vec<AuctionFilter>),
+	/// The filter doesn't match
+	Not(Box<AuctionFilter>),
+}
+
+// Deriving this needs `#[default]`, which is newer than the rest of the crate requires.
+#[allow(clippy::derivable_impls)]
+impl Default for AuctionFilter {
+	fn default() -> Self {
+		AuctionFilter::Any
+	}
+}
+
+impl AuctionFilter {
+	pub fn name_contains(text: &str) -> AuctionFilter {
+		AuctionFilter::NameContains(text.to_lowercase())
+	}
+
+	pub fn extra_contains(text: &str) -> AuctionFilter {
+		AuctionFilter::ExtraContains(text.to_lowercase())
+	}
+
+	#[cfg(feature = "regex")]
+	pub fn name_matches(expr: &str) -> Result<AuctionFilter> {
+		Ok(AuctionFilter::NameMatches(Regex::new(expr)?))
+	}
+
+	#[cfg(feature = "regex")]
+	pub fn extra_matches(expr: &str) -> Result<AuctionFilter> {
+		Ok(AuctionFilter::ExtraMatches(Regex::new(expr)?))
+	}
+
+	pub fn category(category: Category) -> AuctionFilter {
+		AuctionFilter::Category(category)
+	}
+
+	pub fn tier(min: Rarity, max: Rarity) -> AuctionFilter {
+		AuctionFilter::Tier(min, max)
+	}
+
+	pub fn price(min: i64, max: i64) -> AuctionFilter {
+		AuctionFilter::Price(min, max)
+	}
+
+	pub fn bin() -> AuctionFilter {
+		AuctionFilter::Bin
+	}
+
+	pub fn ending_within(duration: Duration) -> AuctionFilter {
+		AuctionFilter::EndingWithin(duration)
+	}
+
+	#[cfg(feature = "nbt")]
+	pub fn enchantment(name: &str, min_level: i32) -> AuctionFilter {
+		AuctionFilter::Enchantment(name.to_owned(), min_level)
+	}
+
+	pub fn auctioneer(profile: PartialProfile) -> AuctionFilter {
+		AuctionFilter::Auctioneer(profile)
+	}
+
+	/// Combines with another filter, matching only if both match.
+	pub fn and(self, other: AuctionFilter) -> AuctionFilter {
+		match self {
+			AuctionFilter::Any => other,
+			AuctionFilter::And(mut filters) => {
+				filters.push(other);
+				AuctionFilter::And(filters)
+			}
+			filter => AuctionFilter::And(vec![filter, other])
+		}
+	}
+
+	/// Combines with another filter, matching if either matches.
+	pub fn or(self, other: AuctionFilter) -> AuctionFilter {
+		match self {
+			AuctionFilter::Or(mut filters) => {
+				filters.push(other);
+				AuctionFilter::Or(filters)
+			}
+			filter => AuctionFilter::Or(vec![filter, other])
+		}
+	}
+
+	pub fn matches(&self, auction: &Auction) -> bool {
+		self.matches_at(auction, SystemTime::now())
+	}
+
+	/// Evaluates the filter as though it were `now`, which only affects time-based filters.
+	pub fn matches_at(&self, auction: &Auction, now: SystemTime) -> bool {
+		match self {
+			AuctionFilter::Any => true,
+			AuctionFilter::NameContains(text) => auction.item.name.to_lowercase().contains(text),
+			AuctionFilter::ExtraContains(text) => auction.item.extra.to_lowercase().contains(text),
+			#[cfg(feature = "regex")]
+			AuctionFilter::NameMatches(expr) => expr.is_match(&auction.item.name),
+			#[cfg(feature = "regex")]
+			AuctionFilter::ExtraMatches(expr) => expr.is_match(&auction.item.extra),
+			AuctionFilter::Category(category) => &auction.item.category == category,
+			AuctionFilter::Tier(min, max) => (min.rank()..=max.rank()).contains(&auction.item.tier.rank()),
+			AuctionFilter::Price(min, max) => {
+				let price = auction.bids.highest.max(auction.bids.starting);
+
+				(min..=max).contains(&&price)
+			}
+			AuctionFilter::Bin => auction.bin,
+			AuctionFilter::EndingWithin(duration) => auction.ending_within(*duration, now),
+			#[cfg(feature = "nbt")]
+			AuctionFilter::Enchantment(name, min_level) => {
+				let level = auction.item.to_nbt().ok()
+					.and_then(|nbt| nbt.i.into_iter().next())
+					.and_then(|pnbt| pnbt.tag.extra_attributes.enchantments)
+					.and_then(|enchants| enchants.get(name).copied());
+
+				matches!(level, Some(level) if level >= *min_level)
+			}
+			AuctionFilter::Auctioneer(profile) => &auction.auctioneer == profile,
+			AuctionFilter::And(filters) => filters.iter().all(|filter| filter.matches_at(auction, now)),
+			AuctionFilter::Or(filters) => filters.iter().any(|filter| filter.matches_at(auction, now)),
+			AuctionFilter::Not(filter) => !filter.matches_at(auction, now),
+		}
+	}
+
+	/// Wraps a callback for `iter_active_auctions`, so that it's only called with matching auctions.
+	pub fn on_match<F>(self, mut f: F) -> impl FnMut(Auction) -> Result<()> where
+		F: FnMut(Auction) -> Result<()> {
+		move |auction| {
+			if self.matches(&auction) {
+				f(auction)
+			} else {
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Not for AuctionFilter {
+	type Output = AuctionFilter;
+
+	fn not(self) -> AuctionFilter {
+		match self {
+			AuctionFilter::Not(filter) => *filter,
+			filter => AuctionFilter::Not(Box::new(filter))
+		}
+	}
+}
@@ -0,0 +1,146 @@
+use crate::objects::auction::{Auction, PartialAuction};
+use std::collections::HashMap;
+
+/// Pricing statistics for a single item, keyed by `Item::key`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct IndexEntry {
+	/// The cheapest BIN listing of the item
+	pub lowest_bin: Option<i64>,
+	/// The auction holding the cheapest BIN listing
+	pub lowest_bin_auction: Option<PartialAuction>,
+	/// The second cheapest BIN listing of the item
+	pub second_lowest_bin: Option<i64>,
+	/// The number of listings of the item, BIN or otherwise
+	pub listings: usize,
+	/// The number of BIN listings of the item
+	pub bin_listings: usize,
+	/// The median price of the BIN listings of the item
+	pub median_bin: Option<i64>,
+}
+
+/// An index of the lowest BIN prices on the auction house, built from a full scan.
+/// Prices are per listing, not per item in a stack.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct AuctionIndex {
+	/// The `last_update` of the auction pages the index was built from
+	last_update: i64,
+	entries: HashMap<String, IndexEntry>,
+}
+
+impl AuctionIndex {
+	/// Builds an index from a complete scan of the auction house.
+	pub fn build<'a, I>(last_update: i64, auctions: I) -> AuctionIndex where
+		I: IntoIterator<Item = &'a Auction> {
+		let mut builder = AuctionIndexBuilder::new(last_update);
+
+		for auction in auctions {
+			builder.push(auction);
+		}
+
+		builder.finish()
+	}
+
+	/// The `last_update` of the auction pages the index was built from.
+	pub fn last_update(&self) -> i64 {
+		self.last_update
+	}
+
+	pub fn get(&self, key: &str) -> Option<&IndexEntry> {
+		self.entries.get(key)
+	}
+
+	pub fn lowest_bin(&self, key: &str) -> Option<i64> {
+		self.get(key)?.lowest_bin
+	}
+
+	pub fn entries(&self) -> impl Iterator<Item = (&String, &IndexEntry)> {
+		self.entries.iter()
+	}
+
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Swaps in an index built from a more recent scan, leaving this one untouched otherwise.
+	/// Returns whether the index was replaced.
+	pub fn update(&mut self, newer: AuctionIndex) -> bool {
+		if newer.last_update > self.last_update {
+			*self = newer;
+			true
+		} else {
+			false
+		}
+	}
+}
+
+/// Accumulates auctions into an `AuctionIndex` one at a time,
+/// which suits the callback passed to `iter_active_auctions`.
+#[derive(Debug, Clone, Default)]
+pub struct AuctionIndexBuilder {
+	last_update: i64,
+	listings: HashMap<String, Listings>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Listings {
+	count: usize,
+	bins: Vec<(i64, PartialAuction)>,
+}
+
+impl AuctionIndexBuilder {
+	pub fn new(last_update: i64) -> AuctionIndexBuilder {
+		AuctionIndexBuilder {
+			last_update,
+			listings: HashMap::new(),
+		}
+	}
+
+	pub fn push(&mut self, auction: &Auction) {
+		let listings = self.listings.entry(auction.item.key()).or_default();
+		listings.count += 1;
+
+		if auction.bin {
+			listings.bins.push((auction.bids.starting, auction.uuid.clone()));
+		}
+	}
+
+	pub fn finish(self) -> AuctionIndex {
+		let entries = self.listings.into_iter()
+			.map(|(key, mut listings)| {
+				listings.bins.sort_by_key(|(price, _)| *price);
+				let bins = &listings.bins;
+
+				let entry = IndexEntry {
+					lowest_bin: bins.first().map(|(price, _)| *price),
+					lowest_bin_auction: bins.first().map(|(_, uuid)| uuid.clone()),
+					second_lowest_bin: bins.get(1).map(|(price, _)| *price),
+					listings: listings.count,
+					bin_listings: bins.len(),
+					median_bin: median(bins),
+				};
+
+				(key, entry)
+			})
+			.collect();
+
+		AuctionIndex {
+			last_update: self.last_update,
+			entries,
+		}
+	}
+}
+
+/// The median of a sorted set of listings, averaging the middle two of an even count.
+fn median(bins: &[(i64, PartialAuction)]) -> Option<i64> {
+	let mid = bins.len() / 2;
+
+	match bins.len() {
+		0 => None,
+		n if n % 2 == 0 => Some((bins[mid - 1].0 + bins[mid].0) / 2),
+		_ => Some(bins[mid].0)
+	}
+}
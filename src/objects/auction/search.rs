@@ -0,0 +1,162 @@
+use crate::objects::auction::{Auction, PartialAuction};
+use crate::objects::auction::sync::AuctionDiff;
+use std::collections::{BTreeMap, HashMap};
+
+/// How much a term weighs, depending on the field it appeared in.
+const NAME_WEIGHT: f64 = 3.0;
+const EXTRA_WEIGHT: f64 = 2.0;
+const LORE_WEIGHT: f64 = 1.0;
+
+/// An auction matching a search, and how well it matched.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SearchHit {
+	pub auction: PartialAuction,
+	pub score: f64,
+}
+
+/// An inverted index over the name, `extra` and lore of the items on auction.
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+	/// The weight of every auction containing a term, keyed by term
+	terms: BTreeMap<String, HashMap<PartialAuction, f64>>,
+	/// The terms in every auction, so that it can be removed from the index
+	documents: HashMap<PartialAuction, Vec<String>>,
+}
+
+/// Splits text into lowercase alphanumeric terms.
+pub fn tokenize(text: &str) -> Vec<String> {
+	text.split(|c: char| !c.is_alphanumeric())
+		.filter(|term| !term.is_empty())
+		.map(|term| term.to_lowercase())
+		.collect()
+}
+
+impl SearchIndex {
+	pub fn new() -> SearchIndex {
+		Self::default()
+	}
+
+	/// Builds an index over a snapshot of the auction house.
+	pub fn build<'a, I>(auctions: I) -> SearchIndex where
+		I: IntoIterator<Item = &'a Auction> {
+		let mut index = Self::new();
+
+		for auction in auctions {
+			index.insert(auction);
+		}
+
+		index
+	}
+
+	/// Adds an auction to the index, replacing it if it's already present.
+	pub fn insert(&mut self, auction: &Auction) {
+		self.remove(&auction.uuid);
+
+		let mut weights: HashMap<String, f64> = HashMap::new();
+		let fields = [
+			(auction.item.name.clone(), NAME_WEIGHT),
+			(auction.item.extra.clone(), EXTRA_WEIGHT),
+			(auction.item.plain_lore(), LORE_WEIGHT),
+		];
+
+		for (text, weight) in fields.iter() {
+			for term in tokenize(text) {
+				*weights.entry(term).or_default() += weight;
+			}
+		}
+
+		for (term, weight) in &weights {
+			self.terms.entry(term.clone()).or_default().insert(auction.uuid.clone(), *weight);
+		}
+
+		self.documents.insert(auction.uuid.clone(), weights.into_keys().collect());
+	}
+
+	/// Removes an auction from the index, returning whether it was present.
+	pub fn remove(&mut self, uuid: &PartialAuction) -> bool {
+		let terms = match self.documents.remove(uuid) {
+			Some(terms) => terms,
+			None => return false
+		};
+
+		for term in terms {
+			if let Some(postings) = self.terms.get_mut(&term) {
+				postings.remove(uuid);
+
+				if postings.is_empty() {
+					self.terms.remove(&term);
+				}
+			}
+		}
+
+		true
+	}
+
+	/// Brings the index up to date with the changes from an `AuctionSync`.
+	/// Bids don't change the text of an auction, so only listings and removals are applied.
+	pub fn apply(&mut self, diff: &AuctionDiff) {
+		for auction in &diff.removed {
+			self.remove(&auction.uuid);
+		}
+
+		for auction in &diff.added {
+			self.insert(auction);
+		}
+	}
+
+	pub fn len(&self) -> usize {
+		self.documents.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.documents.is_empty()
+	}
+
+	/// Finds the auctions containing every term of the query, best matches first.
+	/// The last term also matches as a prefix, so that partially typed queries find results.
+	pub fn search(&self, query: &str) -> Vec<SearchHit> {
+		let terms = tokenize(query);
+		let mut scores: Option<HashMap<PartialAuction, f64>> = None;
+
+		for (i, term) in terms.iter().enumerate() {
+			let matches = if i + 1 == terms.len() {
+				self.prefix(term)
+			} else {
+				self.terms.get(term).cloned().unwrap_or_default()
+			};
+
+			scores = Some(match scores {
+				None => matches,
+				Some(scores) => scores.into_iter()
+					.filter_map(|(uuid, score)| matches.get(&uuid).map(|weight| (uuid, score + weight)))
+					.collect()
+			});
+		}
+
+		let mut hits: Vec<SearchHit> = scores.unwrap_or_default().into_iter()
+			.map(|(auction, score)| SearchHit { auction, score })
+			.collect();
+
+		hits.sort_by(|a, b| {
+			b.score.partial_cmp(&a.score)
+				.unwrap_or(std::cmp::Ordering::Equal)
+				.then_with(|| a.auction.0.cmp(&b.auction.0))
+		});
+
+		hits
+	}
+
+	/// The best weight of every auction with a term starting with `prefix`.
+	fn prefix(&self, prefix: &str) -> HashMap<PartialAuction, f64> {
+		let mut matches: HashMap<PartialAuction, f64> = HashMap::new();
+
+		for (_, postings) in self.terms.range(prefix.to_owned()..).take_while(|(term, _)| term.starts_with(prefix)) {
+			for (uuid, weight) in postings {
+				let best = matches.entry(uuid.clone()).or_default();
+				*best = best.max(*weight);
+			}
+		}
+
+		matches
+	}
+}
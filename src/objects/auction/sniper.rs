@@ -0,0 +1,115 @@
+use crate::objects::auction::Auction;
+use crate::objects::auction::fees::{FeeSchedule, Listing};
+use crate::objects::auction::index::AuctionIndex;
+use crate::objects::auction::sync::AuctionDiff;
+use crate::objects::items::Modifier;
+use std::time::Duration;
+
+/// The price which new listings are measured against.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Reference {
+	/// The cheapest other BIN listing of the item
+	LowestBin,
+	/// The median of the item's BIN listings
+	MedianBin,
+}
+
+/// An underpriced BIN listing.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Snipe {
+	/// The underpriced auction
+	pub auction: Auction,
+	/// The key of the item, as in `Item::key`
+	pub key: String,
+	/// The price the auction is listed at
+	pub price: i64,
+	/// The price the item is expected to resell for
+	pub reference_price: i64,
+	/// The coins left after buying the item and relisting it at the reference price, net of fees
+	pub expected_profit: i64,
+}
+
+/// Flags new BIN listings priced well below what the item usually sells for.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Sniper {
+	/// Listings priced at or below this fraction of the reference price are flagged
+	pub threshold: f64,
+	/// The price which listings are measured against
+	pub reference: Reference,
+	/// Listings with any of these modifiers are ignored, as they aren't comparable to the reference price
+	pub excluded_modifiers: Vec<Modifier>,
+	/// Listings of these items, by `Item::key`, are ignored
+	pub excluded_items: Vec<String>,
+	/// The least expected profit for a listing to be flagged
+	pub min_profit: i64,
+	/// The fees paid when relisting a sniped item
+	pub fees: FeeSchedule,
+	/// How long a sniped item is relisted for
+	pub relist_duration: Duration,
+}
+
+impl Default for Sniper {
+	fn default() -> Self {
+		Sniper {
+			threshold: 0.8,
+			reference: Reference::LowestBin,
+			excluded_modifiers: vec![Modifier::Enchanted, Modifier::Recombobulated, Modifier::Starred, Modifier::HotPotatoBooks],
+			excluded_items: vec![],
+			min_profit: 0,
+			fees: FeeSchedule::default(),
+			relist_duration: Duration::from_secs(48 * 60 * 60),
+		}
+	}
+}
+
+impl Sniper {
+	/// Checks a single listing against an index of the auction house.
+	pub fn check(&self, auction: &Auction, index: &AuctionIndex) -> Option<Snipe> {
+		if !auction.bin {
+			return None;
+		}
+
+		let key = auction.item.key();
+		if self.excluded_items.contains(&key) {
+			return None;
+		}
+
+		let entry = index.get(&key)?;
+		let reference_price = match self.reference {
+			// The listing itself is likely in the index, in which case the next cheapest is the reference.
+			Reference::LowestBin if entry.lowest_bin_auction.as_ref() == Some(&auction.uuid) => entry.second_lowest_bin?,
+			Reference::LowestBin => entry.lowest_bin?,
+			Reference::MedianBin => entry.median_bin?,
+		};
+
+		let price = auction.bids.starting;
+		if price as f64 > reference_price as f64 * self.threshold {
+			return None;
+		}
+
+		if auction.item.modifiers().iter().any(|modifier| self.excluded_modifiers.contains(modifier)) {
+			return None;
+		}
+
+		let resale = self.fees.proceeds(&Listing::bin(reference_price, self.relist_duration));
+		let expected_profit = resale.net - price;
+		if expected_profit < self.min_profit {
+			return None;
+		}
+
+		Some(Snipe {
+			auction: auction.clone(),
+			key,
+			price,
+			reference_price,
+			expected_profit,
+		})
+	}
+
+	/// Checks every listing added in a diff.
+	pub fn scan(&self, diff: &AuctionDiff, index: &AuctionIndex) -> Vec<Snipe> {
+		diff.added.iter()
+			.filter_map(|auction| self.check(auction, index))
+			.collect()
+	}
+}
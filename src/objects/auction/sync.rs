@@ -0,0 +1,112 @@
+use crate::objects::auction::{Auction, Bid, PartialAuction};
+use std::collections::HashMap;
+
+/// A change in the bids of an auction that was listed in both snapshots.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BidChange {
+	/// The auction as of the newer snapshot
+	pub auction: Auction,
+	/// The highest bid as of the older snapshot
+	pub previous_highest: i64,
+	/// The bids placed between the snapshots
+	pub new_bids: Vec<Bid>,
+}
+
+/// The changes between two complete scans of the auction house.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct AuctionDiff {
+	/// The `last_update` of the newer scan
+	pub last_update: i64,
+	/// Auctions listed since the older scan
+	pub added: Vec<Auction>,
+	/// Auctions no longer listed, as of the older scan; they have either ended or been bought
+	pub removed: Vec<Auction>,
+	/// Auctions with bids placed since the older scan
+	pub bid_changes: Vec<BidChange>,
+}
+
+impl AuctionDiff {
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.bid_changes.is_empty()
+	}
+}
+
+/// The latest known snapshot of the auction house, keyed by auction.
+/// Feeding it complete scans turns them into a change feed of `AuctionDiff`s.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct AuctionSync {
+	last_update: i64,
+	auctions: HashMap<PartialAuction, Auction>,
+}
+
+impl AuctionSync {
+	pub fn new() -> AuctionSync {
+		Self::default()
+	}
+
+	/// The `last_update` of the current snapshot, zero if nothing has been synced yet.
+	pub fn last_update(&self) -> i64 {
+		self.last_update
+	}
+
+	/// Whether a page reporting `last_update` is newer than the current snapshot.
+	pub fn is_stale(&self, last_update: i64) -> bool {
+		last_update > self.last_update
+	}
+
+	pub fn get(&self, uuid: &PartialAuction) -> Option<&Auction> {
+		self.auctions.get(uuid)
+	}
+
+	pub fn auctions(&self) -> impl Iterator<Item = &Auction> {
+		self.auctions.values()
+	}
+
+	pub fn len(&self) -> usize {
+		self.auctions.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.auctions.is_empty()
+	}
+
+	/// Replaces the snapshot with a complete scan and returns what changed.
+	/// Auctions repeated within the scan, e.g. from pages shifting between requests,
+	/// only count once.
+	pub fn apply<I>(&mut self, last_update: i64, auctions: I) -> AuctionDiff where
+		I: IntoIterator<Item = Auction> {
+		let mut previous = std::mem::take(&mut self.auctions);
+		let mut diff = AuctionDiff {
+			last_update,
+			..AuctionDiff::default()
+		};
+
+		for auction in auctions {
+			if self.auctions.contains_key(&auction.uuid) {
+				continue;
+			}
+
+			match previous.remove(&auction.uuid) {
+				None => diff.added.push(auction.clone()),
+				Some(old) if old.bids.bids.len() != auction.bids.bids.len() || old.bids.highest != auction.bids.highest => {
+					diff.bid_changes.push(BidChange {
+						auction: auction.clone(),
+						previous_highest: old.bids.highest,
+						new_bids: auction.bids.bids.iter()
+							.filter(|bid| !old.bids.bids.contains(bid))
+							.cloned()
+							.collect(),
+					});
+				}
+				Some(_) => {}
+			}
+
+			self.auctions.insert(auction.uuid.clone(), auction);
+		}
+
+		diff.removed = previous.into_values().collect();
+		self.last_update = last_update;
+
+		diff
+	}
+}
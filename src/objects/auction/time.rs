@@ -0,0 +1,74 @@
+use crate::objects::auction::{Auction, Bid, GlobalAuctions};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Converts Unix time in milliseconds, as used throughout the API, to a `SystemTime`.
+pub fn from_millis(millis: i64) -> SystemTime {
+	if millis >= 0 {
+		UNIX_EPOCH + Duration::from_millis(millis as u64)
+	} else {
+		UNIX_EPOCH - Duration::from_millis(millis.unsigned_abs())
+	}
+}
+
+impl Auction {
+	/// The time the auction commenced.
+	pub fn start_time(&self) -> SystemTime {
+		from_millis(self.start)
+	}
+
+	/// The time the auction is currently projected to end.
+	pub fn end_time(&self) -> SystemTime {
+		from_millis(self.end)
+	}
+
+	/// The time left until the projected end of the auction, zero if it has ended.
+	pub fn time_remaining(&self, now: SystemTime) -> Duration {
+		self.end_time().duration_since(now).unwrap_or_default()
+	}
+
+	/// Whether the projected end of the auction has passed.
+	pub fn is_ended(&self, now: SystemTime) -> bool {
+		self.end_time() <= now
+	}
+
+	/// Whether the auction is projected to end within `duration` of `now`, or has ended already.
+	pub fn ending_within(&self, duration: Duration, now: SystemTime) -> bool {
+		self.time_remaining(now) <= duration
+	}
+
+	#[cfg(feature = "chrono")]
+	pub fn start_datetime(&self) -> DateTime<Utc> {
+		self.start_time().into()
+	}
+
+	#[cfg(feature = "chrono")]
+	pub fn end_datetime(&self) -> DateTime<Utc> {
+		self.end_time().into()
+	}
+}
+
+impl Bid {
+	/// The time the bid was placed.
+	pub fn time(&self) -> SystemTime {
+		from_millis(self.timestamp)
+	}
+
+	#[cfg(feature = "chrono")]
+	pub fn datetime(&self) -> DateTime<Utc> {
+		self.time().into()
+	}
+}
+
+impl GlobalAuctions {
+	/// The time of the last update that has occurred in the dataset.
+	pub fn last_update_time(&self) -> SystemTime {
+		from_millis(self.last_update)
+	}
+
+	#[cfg(feature = "chrono")]
+	pub fn last_update_datetime(&self) -> DateTime<Utc> {
+		self.last_update_time().into()
+	}
+}
@@ -0,0 +1,257 @@
+use crate::objects::auction::{Auction, Bid, PartialAuction};
+use crate::objects::auction::filter::AuctionFilter;
+use crate::objects::auction::sync::{AuctionDiff, AuctionSync};
+use crate::objects::profile::PartialProfile;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+
+/// Something on the auction house to keep an eye on.
+#[derive(Debug, Clone)]
+pub enum Watch {
+	/// A single auction
+	Auction(PartialAuction),
+	/// Every auction listed by a profile
+	Auctioneer(PartialProfile),
+	/// Every auction matching a filter
+	Filter(AuctionFilter),
+}
+
+impl Watch {
+	pub fn matches(&self, auction: &Auction) -> bool {
+		match self {
+			Watch::Auction(uuid) => &auction.uuid == uuid,
+			Watch::Auctioneer(profile) => &auction.auctioneer == profile,
+			Watch::Filter(filter) => filter.matches(auction),
+		}
+	}
+}
+
+/// A handle to a registered watch, used to remove it again.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct WatchId(usize);
+
+/// A change to a watched auction.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WatchEvent {
+	/// A watched auction was listed, or was first seen
+	Listed(Auction),
+	/// A bid was placed on a watched auction
+	NewBid {
+		auction: Auction,
+		bid: Bid,
+	},
+	/// The winning bid on a watched auction was beaten by another bidder
+	Outbid {
+		auction: Auction,
+		/// The bid that was winning before
+		previous: Bid,
+		/// The bid that's winning now
+		winning: Bid,
+	},
+	/// A watched auction is projected to end soon
+	EndingSoon(Auction),
+	/// A watched auction has left the auction house, either having ended or been bought
+	Ended(Auction),
+	/// An auction watched with `Watch::Auction` that ended has been claimed
+	Claimed(Auction),
+}
+
+/// A set of watches which turns auction house changes into `WatchEvent`s,
+/// broadcast to every subscriber.
+#[derive(Debug)]
+pub struct Watchlist {
+	watches: HashMap<WatchId, Watch>,
+	next_id: usize,
+	/// How long before the projected end of an auction `EndingSoon` is sent
+	ending_soon: Duration,
+	sender: broadcast::Sender<WatchEvent>,
+	/// Active auctions matched by a watch, so only these are checked for `EndingSoon`
+	tracked: HashSet<PartialAuction>,
+	/// Whether a watch was added since the last `process`, so the whole snapshot is checked for matches
+	rescan: bool,
+	/// Auctions that `EndingSoon` has been sent for
+	ending: HashSet<PartialAuction>,
+	/// Ended auctions watched with `Watch::Auction` that haven't been claimed yet
+	unclaimed: HashMap<PartialAuction, Auction>,
+}
+
+impl Watchlist {
+	/// Creates a watchlist, buffering up to `capacity` events for slow subscribers.
+	pub fn new(capacity: usize, ending_soon: Duration) -> Watchlist {
+		let (sender, _) = broadcast::channel(capacity);
+
+		Watchlist {
+			watches: HashMap::new(),
+			next_id: 0,
+			ending_soon,
+			sender,
+			tracked: HashSet::new(),
+			rescan: false,
+			ending: HashSet::new(),
+			unclaimed: HashMap::new(),
+		}
+	}
+
+	pub fn subscribe(&self) -> broadcast::Receiver<WatchEvent> {
+		self.sender.subscribe()
+	}
+
+	pub fn watch(&mut self, watch: Watch) -> WatchId {
+		let id = WatchId(self.next_id);
+		self.next_id += 1;
+		self.watches.insert(id, watch);
+		self.rescan = true;
+
+		id
+	}
+
+	pub fn unwatch(&mut self, id: WatchId) -> Option<Watch> {
+		let watch = self.watches.remove(&id)?;
+		if let Watch::Auction(uuid) = &watch {
+			if !self.is_watched_directly(uuid) {
+				self.unclaimed.remove(uuid);
+			}
+		}
+
+		Some(watch)
+	}
+
+	pub fn is_watched(&self, auction: &Auction) -> bool {
+		self.watches.values().any(|watch| watch.matches(auction))
+	}
+
+	/// Whether the auction is watched with `Watch::Auction`, rather than only through a broader watch.
+	pub fn is_watched_directly(&self, uuid: &PartialAuction) -> bool {
+		self.watches.values().any(|watch| match watch {
+			Watch::Auction(watched) => watched == uuid,
+			_ => false
+		})
+	}
+
+	/// Ended auctions which are still waiting to be claimed, to be checked with `get_auction`.
+	/// Only auctions watched with `Watch::Auction` are followed up on, as broader watches
+	/// could match more ended auctions than can be looked up.
+	pub fn unclaimed(&self) -> impl Iterator<Item = &PartialAuction> {
+		self.unclaimed.keys()
+	}
+
+	/// Emits the events for the changes from an `AuctionSync`, as well as any watched
+	/// auctions in its snapshot which are ending soon. The events are also returned.
+	/// Only the changes and the auctions already matched are looked at, unless a watch
+	/// has been added since the last call, in which case the whole snapshot is checked once.
+	pub fn process(&mut self, diff: &AuctionDiff, sync: &AuctionSync, now: SystemTime) -> Vec<WatchEvent> {
+		let mut events = vec![];
+
+		if self.rescan {
+			self.rescan = false;
+			self.tracked = sync.auctions()
+				.filter(|auction| self.is_watched(auction))
+				.map(|auction| auction.uuid.clone())
+				.collect();
+		}
+
+		for auction in &diff.added {
+			if self.is_watched(auction) {
+				self.tracked.insert(auction.uuid.clone());
+				events.push(WatchEvent::Listed(auction.clone()));
+			}
+		}
+
+		for change in &diff.bid_changes {
+			let auction = &change.auction;
+			if !self.is_watched(auction) {
+				self.tracked.remove(&auction.uuid);
+				continue;
+			}
+
+			self.tracked.insert(auction.uuid.clone());
+			let mut bids: Vec<&Bid> = auction.bids.bids.iter()
+				.filter(|bid| !change.new_bids.contains(bid))
+				.collect();
+
+			let mut new_bids = change.new_bids.clone();
+			new_bids.sort_by_key(|bid| bid.timestamp);
+
+			for bid in &new_bids {
+				let previous = bids.iter().max_by_key(|bid| bid.amount).map(|bid| (*bid).clone());
+
+				events.push(WatchEvent::NewBid {
+					auction: auction.clone(),
+					bid: bid.clone(),
+				});
+
+				if let Some(previous) = previous {
+					if previous.bidder != bid.bidder && bid.amount > previous.amount {
+						events.push(WatchEvent::Outbid {
+							auction: auction.clone(),
+							previous,
+							winning: bid.clone(),
+						});
+					}
+				}
+
+				bids.push(bid);
+			}
+		}
+
+		for auction in &diff.removed {
+			self.tracked.remove(&auction.uuid);
+			if !self.is_watched(auction) {
+				continue;
+			}
+
+			self.ending.remove(&auction.uuid);
+			if self.is_watched_directly(&auction.uuid) {
+				self.unclaimed.insert(auction.uuid.clone(), auction.clone());
+			}
+			events.push(WatchEvent::Ended(auction.clone()));
+		}
+
+		let tracked: Vec<PartialAuction> = self.tracked.iter().cloned().collect();
+		for uuid in tracked {
+			// Watches may have been removed since the auction was matched.
+			let auction = match sync.get(&uuid) {
+				Some(auction) if self.is_watched(auction) => auction,
+				_ => {
+					self.tracked.remove(&uuid);
+					continue;
+				}
+			};
+			if self.ending.contains(&uuid) {
+				continue;
+			}
+
+			let remaining = auction.projected_end_time().duration_since(now).unwrap_or_default();
+			if remaining <= self.ending_soon {
+				self.ending.insert(auction.uuid.clone());
+				events.push(WatchEvent::EndingSoon(auction.clone()));
+			}
+		}
+
+		self.emit(&events);
+
+		events
+	}
+
+	/// Emits `Claimed` if an ended auction has been claimed, given the result of looking it up with `get_auction`.
+	/// Auctions are removed once every party has claimed them, so a missing auction counts as claimed.
+	pub fn process_claim(&mut self, uuid: &PartialAuction, fetched: Option<&Auction>) -> Option<WatchEvent> {
+		if matches!(fetched, Some(auction) if !auction.claim.claimed) {
+			return None;
+		}
+
+		let ended = self.unclaimed.remove(uuid)?;
+		let event = WatchEvent::Claimed(fetched.cloned().unwrap_or(ended));
+		self.emit(std::slice::from_ref(&event));
+
+		Some(event)
+	}
+
+	fn emit(&self, events: &[WatchEvent]) {
+		for event in events {
+			// Sending only fails when nobody is subscribed, in which case the event has nowhere to go.
+			let _ = self.sender.send(event.clone());
+		}
+	}
+}
@@ -1,3 +1,8 @@
+pub mod analysis;
+pub mod depth;
+pub mod diff;
+pub mod history;
+
 /// The ID of the product in question
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct Product {
@@ -16,37 +21,61 @@ pub struct Product {
 /// Current, live data of the auction
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct LiveProductData {
-	/// List of the top (up to 30) buy orders
+	/// List of the cheapest (up to 30) sell offers, which instant buys are filled against.
+	/// Hypixel names the summaries after the instant action, so this is *not* a list of buy orders.
 	pub buy_summary: Vec<Order>,
-	/// List of the top (up to 30) sell offers
+	/// List of the highest (up to 30) buy orders, which instant sells are filled against.
+	/// Hypixel names the summaries after the instant action, so this is *not* a list of sell offers.
 	pub sell_summary: Vec<Order>,
 	/// The current quick stats of a bazaar item
 	pub quick_status: QuickStatus,
 }
 
 impl LiveProductData {
-	pub fn top_buy(&self) -> f64 {
-		let mut top = f64::NEG_INFINITY;
+	/// The price per unit of the highest buy order, i.e. the price an item can be instantly sold for.
+	/// Returns `None` if there are no buy orders.
+	pub fn best_bid(&self) -> Option<f64> {
+		self.sell_summary.iter()
+			.map(|order| order.price_per_unit)
+			.fold(None, |best, price| match best {
+				Some(best) if best >= price => Some(best),
+				_ => Some(price)
+			})
+	}
 
-		for order in &self.buy_summary {
-			if top < order.price_per_unit {
-				top = order.price_per_unit
-			}
-		}
+	/// The price per unit of the cheapest sell offer, i.e. the price an item can be instantly bought for.
+	/// Returns `None` if there are no sell offers.
+	pub fn best_ask(&self) -> Option<f64> {
+		self.buy_summary.iter()
+			.map(|order| order.price_per_unit)
+			.fold(None, |best, price| match best {
+				Some(best) if best <= price => Some(best),
+				_ => Some(price)
+			})
+	}
 
-		return top;
+	/// The difference between the best ask and the best bid.
+	/// Returns `None` if either side of the book is empty.
+	pub fn spread(&self) -> Option<f64> {
+		Some(self.best_ask()? - self.best_bid()?)
 	}
 
-	pub fn top_sell(&self) -> f64 {
-		let mut top = f64::INFINITY;
+	/// The midpoint between the best bid and the best ask.
+	/// Returns `None` if either side of the book is empty.
+	pub fn mid_price(&self) -> Option<f64> {
+		Some((self.best_ask()? + self.best_bid()?) / 2.0)
+	}
 
-		for order in &self.sell_summary {
-			if top > order.price_per_unit {
-				top = order.price_per_unit
-			}
-		}
+	/// The price per unit of the highest buy order, or negative infinity if there are none.
+	#[deprecated(note = "use `best_bid` instead")]
+	pub fn top_buy(&self) -> f64 {
+		self.best_bid().unwrap_or(f64::NEG_INFINITY)
+	}
 
-		return top;
+	/// The price per unit of the cheapest sell offer, or infinity if there are none.
+	#[deprecated(note = "use `best_ask` instead")]
+	pub fn top_sell(&self) -> f64 {
+		self.best_ask().unwrap_or(f64::INFINITY)
 	}
 }
 
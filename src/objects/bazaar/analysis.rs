@@ -0,0 +1,89 @@
+use crate::objects::bazaar::Product;
+use std::collections::HashMap;
+
+/// Hours in the week covered by `buy_moving_week`/`sell_moving_week`.
+const HOURS_PER_WEEK: f64 = 168.0;
+
+/// The tax the bazaar deducts from the coins made by selling an item.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct BazaarTax {
+	/// The fraction of every sale kept by the bazaar
+	pub rate: f64,
+}
+
+impl BazaarTax {
+	pub fn new(rate: f64) -> BazaarTax {
+		BazaarTax { rate }
+	}
+
+	/// The tax after `tiers` levels of the Bazaar Flipper community upgrade,
+	/// each of which knocks 0.125% off the base rate of 1.25%.
+	pub fn with_flipper_upgrades(tiers: u8) -> BazaarTax {
+		Self::new((0.0125 - 0.00125 * tiers as f64).max(0.0))
+	}
+
+	/// The coins kept from a sale of `gross` coins.
+	pub fn net(&self, gross: f64) -> f64 {
+		gross * (1.0 - self.rate)
+	}
+}
+
+impl Default for BazaarTax {
+	fn default() -> Self {
+		Self::with_flipper_upgrades(0)
+	}
+}
+
+/// The estimated profitability of flipping a product: buying through a buy order
+/// and selling the items back through a sell offer.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Flip {
+	/// The ID of the product flipped
+	pub product_id: String,
+	/// The price per unit paid through the buy order
+	pub buy_price: f64,
+	/// The price per unit asked for through the sell offer
+	pub sell_price: f64,
+	/// The coins made per unit after tax
+	pub margin: f64,
+	/// The margin as a fraction of the buy price
+	pub margin_ratio: f64,
+	/// The number of items that can be expected to move through both sides of the book per hour
+	pub hourly_volume: f64,
+	/// The coins that can be expected to be made per hour
+	pub hourly_profit: f64,
+}
+
+impl Flip {
+	/// Evaluates a flip on a single product.
+	/// Returns `None` if either side of the product's order book is empty, or the buy orders are free.
+	pub fn evaluate(product: &Product, tax: &BazaarTax) -> Option<Flip> {
+		let buy_price = product.live_data.best_bid().filter(|price| *price > 0.0)?;
+		let sell_price = product.live_data.best_ask()?;
+		let status = &product.live_data.quick_status;
+		let margin = tax.net(sell_price) - buy_price;
+		let hourly_volume = status.buy_moving_week.min(status.sell_moving_week) as f64 / HOURS_PER_WEEK;
+
+		Some(Flip {
+			product_id: product.product_id.clone(),
+			buy_price,
+			sell_price,
+			margin,
+			margin_ratio: margin / buy_price,
+			hourly_volume,
+			hourly_profit: margin * hourly_volume,
+		})
+	}
+}
+
+/// Evaluates a flip on every product in a listing, such as the one returned by
+/// `get_bazaar_product_listing`, with the most profitable per hour first.
+pub fn rank_flips(products: &HashMap<String, Product>, tax: &BazaarTax) -> Vec<Flip> {
+	let mut flips: Vec<Flip> = products.values()
+		.filter_map(|product| Flip::evaluate(product, tax))
+		.collect();
+
+	flips.sort_by(|a, b| b.hourly_profit.partial_cmp(&a.hourly_profit).unwrap_or(std::cmp::Ordering::Equal));
+
+	flips
+}
@@ -0,0 +1,165 @@
+use crate::objects::bazaar::{LiveProductData, Order};
+
+/// The result of walking one side of a product's order book for a given quantity.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Fill {
+	/// The quantity that the listed orders were able to fill
+	pub filled: i64,
+	/// The quantity left over once the listed orders (up to 30) were exhausted
+	pub unfilled: i64,
+	/// The total coins paid (or received) for the filled quantity
+	pub total: f64,
+	/// The average price per unit of the filled quantity
+	pub average_price: f64,
+	/// The price per unit of the best order on this side of the book
+	pub top_price: f64,
+	/// The price per unit of the last order touched by the fill
+	pub worst_price: f64,
+	/// How much worse the average price is than the top price, as a fraction of the top price
+	pub slippage: f64,
+}
+
+/// A point on a cumulative depth curve.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct DepthPoint {
+	/// The price per unit of this level
+	pub price: f64,
+	/// Sum of item amounts from the top of the book up to and including this level
+	pub amount: i64,
+	/// Sum of coins from the top of the book up to and including this level
+	pub total: f64,
+}
+
+impl LiveProductData {
+	/// Simulates instantly buying `quantity` items, filling against the sell offers
+	/// in `buy_summary` from the cheapest price up.
+	/// Returns `None` if the quantity isn't positive or there is nothing to buy.
+	pub fn instant_buy(&self, quantity: i64) -> Option<Fill> {
+		fill(&sorted(&self.buy_summary, true), quantity, true)
+	}
+
+	/// Simulates instantly selling `quantity` items, filling against the buy orders
+	/// in `sell_summary` from the highest price down.
+	/// Returns `None` if the quantity isn't positive or there is nothing to sell to.
+	pub fn instant_sell(&self, quantity: i64) -> Option<Fill> {
+		fill(&sorted(&self.sell_summary, false), quantity, false)
+	}
+
+	/// The cumulative depth of `buy_summary`, ordered from the cheapest price up.
+	pub fn buy_depth(&self) -> Vec<DepthPoint> {
+		depth(&sorted(&self.buy_summary, true))
+	}
+
+	/// The cumulative depth of `sell_summary`, ordered from the highest price down.
+	pub fn sell_depth(&self) -> Vec<DepthPoint> {
+		depth(&sorted(&self.sell_summary, false))
+	}
+
+	/// The quantity that can be instantly bought within `fraction` (e.g. `0.02` for 2%) of the cheapest price.
+	pub fn buy_amount_within(&self, fraction: f64) -> i64 {
+		let levels = sorted(&self.buy_summary, true);
+
+		match levels.first() {
+			Some(top) => {
+				let limit = top.price_per_unit * (1.0 + fraction);
+
+				levels.iter()
+					.take_while(|order| order.price_per_unit <= limit)
+					.map(|order| order.amount as i64)
+					.sum()
+			}
+			None => 0
+		}
+	}
+
+	/// The quantity that can be instantly sold within `fraction` (e.g. `0.02` for 2%) of the highest price.
+	pub fn sell_amount_within(&self, fraction: f64) -> i64 {
+		let levels = sorted(&self.sell_summary, false);
+
+		match levels.first() {
+			Some(top) => {
+				let limit = top.price_per_unit * (1.0 - fraction);
+
+				levels.iter()
+					.take_while(|order| order.price_per_unit >= limit)
+					.map(|order| order.amount as i64)
+					.sum()
+			}
+			None => 0
+		}
+	}
+}
+
+fn sorted(orders: &[Order], ascending: bool) -> Vec<&Order> {
+	let mut levels: Vec<&Order> = orders.iter().collect();
+
+	levels.sort_by(|a, b| {
+		let ord = a.price_per_unit.partial_cmp(&b.price_per_unit).unwrap_or(std::cmp::Ordering::Equal);
+
+		if ascending { ord } else { ord.reverse() }
+	});
+
+	levels
+}
+
+fn fill(levels: &[&Order], quantity: i64, buying: bool) -> Option<Fill> {
+	if quantity <= 0 {
+		return None;
+	}
+
+	let top_price = levels.first()?.price_per_unit;
+	let mut remaining = quantity;
+	let mut total = 0.0;
+	let mut worst_price = top_price;
+
+	for order in levels {
+		if remaining == 0 {
+			break;
+		}
+
+		let taken = remaining.min(order.amount as i64);
+		total += taken as f64 * order.price_per_unit;
+		worst_price = order.price_per_unit;
+		remaining -= taken;
+	}
+
+	let filled = quantity - remaining;
+	if filled == 0 {
+		return None;
+	}
+
+	let average_price = total / filled as f64;
+	let slippage = if buying {
+		(average_price - top_price) / top_price
+	} else {
+		(top_price - average_price) / top_price
+	};
+
+	Some(Fill {
+		filled,
+		unfilled: remaining,
+		total,
+		average_price,
+		top_price,
+		worst_price,
+		slippage,
+	})
+}
+
+fn depth(levels: &[&Order]) -> Vec<DepthPoint> {
+	let mut amount = 0;
+	let mut total = 0.0;
+
+	levels.iter()
+		.map(|order| {
+			amount += order.amount as i64;
+			total += order.amount as f64 * order.price_per_unit;
+
+			DepthPoint {
+				price: order.price_per_unit,
+				amount,
+				total,
+			}
+		})
+		.collect()
+}
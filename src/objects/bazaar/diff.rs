@@ -0,0 +1,142 @@
+use crate::objects::bazaar::{Order, Product};
+use std::collections::HashMap;
+
+/// The most price levels Hypixel lists on either side of a product's book.
+const MAX_LEVELS: usize = 30;
+
+/// A change in the items listed at a single price level.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LevelChange {
+	/// The price per unit of the level
+	pub price: f64,
+	/// The change in the amount of items listed at this price
+	pub amount: i64,
+	/// The change in the number of orders listed at this price
+	pub orders: i64,
+}
+
+/// The changes to a single product between two snapshots of the bazaar.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ProductDiff {
+	/// The ID of the product compared
+	pub product_id: String,
+	/// Sell offer (`buy_summary`) levels that grew, ordered from the cheapest price up
+	pub offers_added: Vec<LevelChange>,
+	/// Sell offer (`buy_summary`) levels that shrank, either from being filled or cancelled
+	pub offers_removed: Vec<LevelChange>,
+	/// Buy order (`sell_summary`) levels that grew, ordered from the highest price down
+	pub bids_added: Vec<LevelChange>,
+	/// Buy order (`sell_summary`) levels that shrank, either from being filled or cancelled
+	pub bids_removed: Vec<LevelChange>,
+	/// Estimated items instantly bought between the snapshots
+	pub instant_buys: f64,
+	/// Estimated items instantly sold between the snapshots
+	pub instant_sells: f64,
+}
+
+/// The changes between two snapshots returned by `get_bazaar_product_listing`.
+/// Hypixel doesn't expose bazaar trades, so these are inferred from the order books and weekly volumes.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct BazaarDiff {
+	/// The changes to every product present in both snapshots, keyed by product ID
+	pub products: HashMap<String, ProductDiff>,
+}
+
+impl BazaarDiff {
+	/// Compares every product present in both the `old` and `new` snapshots.
+	pub fn between(old: &HashMap<String, Product>, new: &HashMap<String, Product>) -> BazaarDiff {
+		let products = new.iter()
+			.filter_map(|(id, product)| {
+				old.get(id).map(|previous| (id.clone(), ProductDiff::between(previous, product)))
+			})
+			.collect();
+
+		BazaarDiff { products }
+	}
+
+	pub fn get(&self, product_id: &str) -> Option<&ProductDiff> {
+		self.products.get(product_id)
+	}
+}
+
+impl ProductDiff {
+	/// Compares two snapshots of the same product.
+	///
+	/// Only the top 30 levels of each side are listed, so levels beyond the last price visible
+	/// in either snapshot are ignored rather than reported as added or removed.
+	///
+	/// The instant volumes are the growth of `buy_moving_week`/`sell_moving_week`,
+	/// which also lose the trades that age out of the week; they are therefore a lower bound.
+	pub fn between(old: &Product, new: &Product) -> ProductDiff {
+		let (offers_added, offers_removed) = levels(&old.live_data.buy_summary, &new.live_data.buy_summary, true);
+		let (bids_added, bids_removed) = levels(&old.live_data.sell_summary, &new.live_data.sell_summary, false);
+
+		let old_status = &old.live_data.quick_status;
+		let new_status = &new.live_data.quick_status;
+
+		ProductDiff {
+			product_id: new.product_id.clone(),
+			offers_added,
+			offers_removed,
+			bids_added,
+			bids_removed,
+			instant_buys: (new_status.buy_moving_week - old_status.buy_moving_week).max(0.0) as f64,
+			instant_sells: (new_status.sell_moving_week - old_status.sell_moving_week).max(0.0) as f64,
+		}
+	}
+
+	/// Whether nothing visible changed between the snapshots.
+	pub fn is_empty(&self) -> bool {
+		self.offers_added.is_empty() && self.offers_removed.is_empty()
+			&& self.bids_added.is_empty() && self.bids_removed.is_empty()
+			&& self.instant_buys == 0.0 && self.instant_sells == 0.0
+	}
+}
+
+/// The last price that is guaranteed to be visible, if the summary was cut off at `MAX_LEVELS`.
+fn visible_limit(orders: &[Order], ascending: bool) -> Option<f64> {
+	if orders.len() < MAX_LEVELS {
+		return None;
+	}
+
+	let prices = orders.iter().map(|order| order.price_per_unit);
+
+	Some(if ascending {
+		prices.fold(f64::NEG_INFINITY, f64::max)
+	} else {
+		prices.fold(f64::INFINITY, f64::min)
+	})
+}
+
+fn levels(old: &[Order], new: &[Order], ascending: bool) -> (Vec<LevelChange>, Vec<LevelChange>) {
+	let limits = [visible_limit(old, ascending), visible_limit(new, ascending)];
+	let visible = |price: f64| limits.iter().flatten().all(|&limit| {
+		if ascending { price <= limit } else { price >= limit }
+	});
+
+	let mut book: HashMap<u64, LevelChange> = HashMap::new();
+	for (orders, sign) in [(old, -1), (new, 1)].iter() {
+		for order in orders.iter() {
+			let level = book.entry(order.price_per_unit.to_bits()).or_insert(LevelChange {
+				price: order.price_per_unit,
+				amount: 0,
+				orders: 0,
+			});
+
+			level.amount += *sign * order.amount as i64;
+			level.orders += *sign * order.orders as i64;
+		}
+	}
+
+	let mut changes: Vec<LevelChange> = book.into_values()
+		.filter(|level| (level.amount != 0 || level.orders != 0) && visible(level.price))
+		.collect();
+
+	changes.sort_by(|a, b| {
+		let ord = a.price.partial_cmp(&b.price).unwrap_or(std::cmp::Ordering::Equal);
+
+		if ascending { ord } else { ord.reverse() }
+	});
+
+	changes.into_iter().partition(|level| level.amount > 0 || (level.amount == 0 && level.orders > 0))
+}
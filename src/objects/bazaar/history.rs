@@ -0,0 +1,192 @@
+use crate::objects::bazaar::{Historic, Product, QuickStatus};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// The width of a candle, in milliseconds. Always positive.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(try_from = "i64", into = "i64")]
+pub struct Interval(i64);
+
+impl Interval {
+	pub const MINUTE: Interval = Interval(60 * 1000);
+	pub const FIVE_MINUTES: Interval = Interval(5 * 60 * 1000);
+	pub const HOUR: Interval = Interval(60 * 60 * 1000);
+	pub const DAY: Interval = Interval(24 * 60 * 60 * 1000);
+
+	/// An interval of `millis` milliseconds, `None` unless it's positive.
+	pub fn from_millis(millis: i64) -> Option<Interval> {
+		if millis > 0 {
+			Some(Interval(millis))
+		} else {
+			None
+		}
+	}
+
+	pub fn millis(&self) -> i64 {
+		self.0
+	}
+
+	/// The start of the interval which `timestamp` falls into.
+	pub fn floor(&self, timestamp: i64) -> i64 {
+		timestamp - timestamp.rem_euclid(self.0)
+	}
+}
+
+impl TryFrom<i64> for Interval {
+	type Error = String;
+
+	fn try_from(millis: i64) -> Result<Self, Self::Error> {
+		Self::from_millis(millis).ok_or_else(|| format!("interval must be positive, got {}", millis))
+	}
+}
+
+impl From<Interval> for i64 {
+	fn from(interval: Interval) -> i64 {
+		interval.0
+	}
+}
+
+/// Which of a sample's prices to aggregate.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PriceKind {
+	/// The price paid to instantly buy an item
+	InstantBuy,
+	/// The price received when instantly selling an item
+	InstantSell,
+}
+
+/// The prices of a product at a point in time.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Sample {
+	/// Unix time (in milliseconds) of when the sample was taken
+	pub timestamp: i64,
+	/// The price paid to instantly buy an item
+	pub instant_buy: f64,
+	/// The price received when instantly selling an item
+	pub instant_sell: f64,
+}
+
+impl Sample {
+	/// Samples the weighted top 2% prices of a product's quick status.
+	pub fn from_status(timestamp: i64, status: &QuickStatus) -> Sample {
+		Sample {
+			timestamp,
+			instant_buy: status.buy_price as f64,
+			instant_sell: status.sell_price as f64,
+		}
+	}
+
+	/// Samples the prices transacted in a historic entry.
+	pub fn from_historic(historic: &Historic) -> Sample {
+		Sample {
+			timestamp: historic.timestamp,
+			instant_buy: historic.recent_instant_buy_price() as f64,
+			instant_sell: historic.recent_instant_sell_price() as f64,
+		}
+	}
+
+	pub fn price(&self, kind: PriceKind) -> f64 {
+		match kind {
+			PriceKind::InstantBuy => self.instant_buy,
+			PriceKind::InstantSell => self.instant_sell,
+		}
+	}
+}
+
+/// An OHLC candle aggregated from the samples within an interval.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Candle {
+	/// Unix time (in milliseconds) of the start of the interval
+	pub start: i64,
+	pub open: f64,
+	pub high: f64,
+	pub low: f64,
+	pub close: f64,
+	/// The number of samples aggregated into the candle
+	pub samples: usize,
+}
+
+/// A recorder for the prices of bazaar products, fed by polling `get_bazaar_product_listing`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct PriceHistory {
+	/// The samples of every product, keyed by product ID and ordered by time
+	samples: HashMap<String, Vec<Sample>>,
+}
+
+impl PriceHistory {
+	pub fn new() -> PriceHistory {
+		Self::default()
+	}
+
+	/// Records the quick status of every product in a listing.
+	pub fn record(&mut self, timestamp: i64, products: &HashMap<String, Product>) {
+		for product in products.values() {
+			self.record_status(timestamp, &product.live_data.quick_status);
+		}
+	}
+
+	/// Records the quick status of a single product.
+	pub fn record_status(&mut self, timestamp: i64, status: &QuickStatus) {
+		self.insert(&status.product_id, Sample::from_status(timestamp, status));
+	}
+
+	/// Records a historic entry, such as the ones in `Product::week_historic`.
+	/// Entries without any transactions are skipped as they carry no prices.
+	pub fn record_historic(&mut self, historic: &Historic) {
+		let sample = Sample::from_historic(historic);
+
+		if sample.instant_buy.is_finite() && sample.instant_sell.is_finite() {
+			self.insert(&historic.product_id, sample);
+		}
+	}
+
+	fn insert(&mut self, product_id: &str, sample: Sample) {
+		let samples = self.samples.entry(product_id.to_owned()).or_default();
+		let i = samples.partition_point(|s| s.timestamp <= sample.timestamp);
+
+		samples.insert(i, sample);
+	}
+
+	/// The recorded samples of a product, ordered by time.
+	pub fn samples(&self, product_id: &str) -> &[Sample] {
+		self.samples.get(product_id).map(|s| &s[..]).unwrap_or(&[])
+	}
+
+	/// Drops every sample taken before `timestamp`.
+	pub fn prune(&mut self, timestamp: i64) {
+		for samples in self.samples.values_mut() {
+			samples.retain(|s| s.timestamp >= timestamp);
+		}
+
+		self.samples.retain(|_, samples| !samples.is_empty());
+	}
+
+	/// Aggregates the samples of a product into candles, skipping intervals without any samples.
+	pub fn candles(&self, product_id: &str, interval: Interval, kind: PriceKind) -> Vec<Candle> {
+		let mut candles: Vec<Candle> = vec![];
+
+		for sample in self.samples(product_id) {
+			let start = interval.floor(sample.timestamp);
+			let price = sample.price(kind);
+
+			match candles.last_mut() {
+				Some(candle) if candle.start == start => {
+					candle.high = candle.high.max(price);
+					candle.low = candle.low.min(price);
+					candle.close = price;
+					candle.samples += 1;
+				}
+				_ => candles.push(Candle {
+					start,
+					open: price,
+					high: price,
+					low: price,
+					close: price,
+					samples: 1,
+				})
+			}
+		}
+
+		candles
+	}
+}
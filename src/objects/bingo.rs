@@ -0,0 +1,78 @@
+/// The goals of the current bingo event, from `resources/skyblock/bingo`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BingoCard {
+	#[serde(rename = "lastUpdated")]
+	pub last_updated: i64,
+	/// The event's ID, matching `BingoEvent::key`
+	pub id: i32,
+	pub name: String,
+	/// Unix time (in milliseconds) of when the event started
+	pub start: i64,
+	/// Unix time (in milliseconds) of when the event ends
+	pub end: i64,
+	/// The event's twist, e.g. `NORMAL`
+	pub modifier: String,
+	pub goals: Vec<BingoGoal>,
+}
+
+impl BingoCard {
+	/// The fraction of the card's goals the player has completed.
+	pub fn completion(&self, event: &BingoEvent) -> f64 {
+		if self.goals.is_empty() {
+			return 0.0;
+		}
+
+		let completed = self.goals.iter().filter(|goal| event.is_completed(&goal.id)).count();
+
+		completed as f64 / self.goals.len() as f64
+	}
+
+	/// The goals the player has yet to complete.
+	pub fn remaining<'a>(&'a self, event: &'a BingoEvent) -> impl Iterator<Item = &'a BingoGoal> {
+		self.goals.iter().filter(move |goal| !event.is_completed(&goal.id))
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BingoGoal {
+	pub id: String,
+	pub name: String,
+	/// The goal's description, absent for community goals
+	pub lore: Option<String>,
+	/// Community goals' progress across every player
+	pub progress: Option<i64>,
+	/// Community goals' thresholds for each tier
+	pub tiers: Option<Vec<i64>>,
+	/// The amount needed to complete the goal, where it counts something
+	#[serde(rename = "requiredAmount")]
+	pub required_amount: Option<i64>,
+}
+
+/// A player's progress across every bingo event, from `skyblock/bingo`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BingoPlayer {
+	pub events: Vec<BingoEvent>,
+}
+
+impl BingoPlayer {
+	/// The player's progress in the event with the given ID.
+	pub fn event(&self, key: i32) -> Option<&BingoEvent> {
+		self.events.iter().find(|event| event.key == key)
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BingoEvent {
+	/// The event's ID
+	pub key: i32,
+	/// The bingo points earned in the event
+	pub points: i32,
+	/// The IDs of the goals completed
+	pub completed_goals: Vec<String>,
+}
+
+impl BingoEvent {
+	pub fn is_completed(&self, goal: &str) -> bool {
+		self.completed_goals.iter().any(|id| id == goal)
+	}
+}
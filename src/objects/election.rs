@@ -0,0 +1,86 @@
+/// The current mayor and the state of elections, from `resources/skyblock/election`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ElectionResource {
+	#[serde(rename = "lastUpdated")]
+	pub last_updated: i64,
+	pub mayor: Mayor,
+	/// The election currently being voted on, absent between elections
+	pub current: Option<Election>,
+}
+
+impl ElectionResource {
+	/// The perks in effect right now, from the mayor and their minister.
+	pub fn active_perks(&self) -> Vec<&Perk> {
+		self.mayor.active_perks()
+	}
+
+	/// Whether a perk of this name is in effect right now.
+	pub fn has_perk(&self, name: &str) -> bool {
+		self.active_perks().iter().any(|perk| perk.name.eq_ignore_ascii_case(name))
+	}
+}
+
+/// The elected mayor.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Mayor {
+	/// The mayor's area of interest, e.g. `economist`
+	pub key: String,
+	pub name: String,
+	pub perks: Vec<Perk>,
+	/// The runner-up, who brings along one of their perks
+	pub minister: Option<Minister>,
+	/// The election the mayor won
+	pub election: Election,
+}
+
+impl Mayor {
+	pub fn active_perks(&self) -> Vec<&Perk> {
+		self.perks.iter()
+			.chain(self.minister.as_ref().map(|minister| &minister.perk))
+			.collect()
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Minister {
+	pub key: String,
+	pub name: String,
+	pub perk: Perk,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Election {
+	/// The SkyBlock year the election took place in
+	pub year: i32,
+	pub candidates: Vec<Candidate>,
+}
+
+impl Election {
+	/// The candidate with the most votes.
+	pub fn leader(&self) -> Option<&Candidate> {
+		self.candidates.iter().max_by_key(|candidate| candidate.votes)
+	}
+
+	pub fn total_votes(&self) -> i64 {
+		self.candidates.iter().map(|candidate| candidate.votes).sum()
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Candidate {
+	pub key: String,
+	pub name: String,
+	pub perks: Vec<Perk>,
+	#[serde(default)]
+	pub votes: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Perk {
+	pub name: String,
+	/// The perk's description, with formatting codes
+	pub description: String,
+	/// Whether this perk is the one a minister would bring
+	#[serde(default)]
+	pub minister: bool,
+}
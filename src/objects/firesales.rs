@@ -0,0 +1,43 @@
+use crate::objects::auction::time::from_millis;
+use std::time::SystemTime;
+
+/// The upcoming and active fire sales, from `skyblock/firesales`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct FireSales {
+	pub sales: Vec<FireSale>,
+}
+
+impl FireSales {
+	/// The sales which are open right now.
+	pub fn active(&self, now: SystemTime) -> impl Iterator<Item = &FireSale> {
+		self.sales.iter().filter(move |sale| sale.is_active(now))
+	}
+}
+
+/// A limited run of a cosmetic item, sold for gems.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct FireSale {
+	pub item_id: String,
+	/// Unix time (in milliseconds) of when the sale opens
+	pub start: i64,
+	/// Unix time (in milliseconds) of when the sale closes
+	pub end: i64,
+	/// The number of the item up for sale
+	pub amount: i64,
+	/// The price of the item, in gems
+	pub price: i64,
+}
+
+impl FireSale {
+	pub fn start_time(&self) -> SystemTime {
+		from_millis(self.start)
+	}
+
+	pub fn end_time(&self) -> SystemTime {
+		from_millis(self.end)
+	}
+
+	pub fn is_active(&self, now: SystemTime) -> bool {
+		self.start_time() <= now && now < self.end_time()
+	}
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+/// A profile's garden, from `skyblock/garden`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Garden {
+	/// The ID of the profile owning the garden
+	pub uuid: String,
+	/// The IDs of the plots which have been unlocked, e.g. `beginner_1`
+	#[serde(rename = "unlocked_plots_ids", default)]
+	pub unlocked_plots: Vec<String>,
+	#[serde(default)]
+	pub garden_experience: f64,
+	/// The crop upgrades bought from the desk, keyed by crop
+	#[serde(default)]
+	pub crop_upgrade_levels: HashMap<String, i32>,
+	/// The crops harvested in the garden, keyed by crop
+	#[serde(default)]
+	pub resources_collected: HashMap<String, i64>,
+	#[serde(rename = "commission_data", default)]
+	pub visitors: VisitorStats,
+	pub selected_barn_skin: Option<String>,
+}
+
+impl Garden {
+	pub fn is_plot_unlocked(&self, plot: &str) -> bool {
+		self.unlocked_plots.iter().any(|id| id == plot)
+	}
+
+	/// The level of the crop upgrade for a crop, e.g. `WHEAT`.
+	pub fn crop_upgrade(&self, crop: &str) -> i32 {
+		self.crop_upgrade_levels.get(crop).copied().unwrap_or(0)
+	}
+}
+
+/// How the profile has dealt with garden visitors.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct VisitorStats {
+	/// The times each visitor has come by, keyed by visitor
+	#[serde(default)]
+	pub visits: HashMap<String, i32>,
+	/// The times each visitor's request has been completed, keyed by visitor
+	#[serde(default)]
+	pub completed: HashMap<String, i32>,
+	#[serde(default)]
+	pub total_completed: i32,
+	/// The number of distinct visitors whose requests have been completed
+	#[serde(default)]
+	pub unique_npcs_served: i32,
+}
+
+impl VisitorStats {
+	pub fn total_visits(&self) -> i32 {
+		self.visits.values().sum()
+	}
+
+	/// The fraction of a visitor's visits which had their request completed, `None` if they've never visited.
+	pub fn completion_rate(&self, visitor: &str) -> Option<f64> {
+		let visits = *self.visits.get(visitor).filter(|visits| **visits > 0)?;
+		let completed = self.completed.get(visitor).copied().unwrap_or(0);
+
+		Some(completed as f64 / visits as f64)
+	}
+}
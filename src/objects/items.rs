@@ -2,8 +2,11 @@
 use std::result::Result as StdResult;
 #[cfg(feature = "bytes")]
 use crate::Result;
+use std::cmp::Ordering;
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+/// The tier of an item. Ordered as the game ranks them, see `rank`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Rarity {
 	#[serde(rename = "COMMON")]
 	Common,
@@ -18,9 +21,161 @@ pub enum Rarity {
 	// The new rarity coming out in Dungeons
 	#[serde(rename = "ARTIFACT")]
 	Artifact,
+	#[serde(rename = "MYTHIC")]
+	Mythic,
+	#[serde(rename = "DIVINE")]
+	Divine,
 	// Cakes and Flakes
 	#[serde(rename = "SPECIAL")]
 	Special,
+	#[serde(rename = "VERY_SPECIAL")]
+	VerySpecial,
+	#[serde(rename = "SUPREME")]
+	Supreme,
+	// Admin items and the like
+	#[serde(rename = "UNOBTAINABLE")]
+	Unobtainable,
+}
+
+impl Rarity {
+	/// The position of the rarity in the game's order, from zero for common upwards.
+	/// Artifact and Supreme are the names Mythic and Divine first went by, and share their ranks.
+	pub fn rank(&self) -> u8 {
+		match self {
+			Rarity::Common => 0,
+			Rarity::Uncommon => 1,
+			Rarity::Rare => 2,
+			Rarity::Epic => 3,
+			Rarity::Legendary => 4,
+			Rarity::Mythic | Rarity::Artifact => 5,
+			Rarity::Divine | Rarity::Supreme => 6,
+			Rarity::Special => 7,
+			Rarity::VerySpecial => 8,
+			Rarity::Unobtainable => 9,
+		}
+	}
+
+	/// The name of the rarity as used by the Hypixel API.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Rarity::Common => "COMMON",
+			Rarity::Uncommon => "UNCOMMON",
+			Rarity::Rare => "RARE",
+			Rarity::Epic => "EPIC",
+			Rarity::Legendary => "LEGENDARY",
+			Rarity::Artifact => "ARTIFACT",
+			Rarity::Mythic => "MYTHIC",
+			Rarity::Divine => "DIVINE",
+			Rarity::Special => "SPECIAL",
+			Rarity::VerySpecial => "VERY_SPECIAL",
+			Rarity::Supreme => "SUPREME",
+			Rarity::Unobtainable => "UNOBTAINABLE",
+		}
+	}
+
+	fn is_renamed(&self) -> bool {
+		matches!(self, Rarity::Artifact | Rarity::Supreme)
+	}
+}
+
+/// Orders by rank, with an old name right after the rarity it was renamed to.
+impl Ord for Rarity {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.rank().cmp(&other.rank())
+			.then_with(|| self.is_renamed().cmp(&other.is_renamed()))
+	}
+}
+
+impl PartialOrd for Rarity {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// Removes the minecraft colour and formatting codes, e.g. `§6`, from text.
+pub fn strip_formatting(text: &str) -> String {
+	let mut plain = String::with_capacity(text.len());
+	let mut chars = text.chars();
+
+	while let Some(c) = chars.next() {
+		if c == '§' {
+			chars.next();
+		} else {
+			plain.push(c);
+		}
+	}
+
+	plain
+}
+
+/// The auction house category of an item.
+/// No Skyblock endpoint filters by category, so its `Display` form is only there
+/// for building query strings should one come to.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum Category {
+	Weapon,
+	Armor,
+	Accessories,
+	Consumables,
+	Blocks,
+	Misc,
+	/// A category this library doesn't know of yet
+	Unknown(String),
+}
+
+impl Category {
+	/// The name of the category as used by the Hypixel API.
+	pub fn as_str(&self) -> &str {
+		match self {
+			Category::Weapon => "weapon",
+			Category::Armor => "armor",
+			Category::Accessories => "accessories",
+			Category::Consumables => "consumables",
+			Category::Blocks => "blocks",
+			Category::Misc => "misc",
+			Category::Unknown(category) => category,
+		}
+	}
+}
+
+impl From<String> for Category {
+	fn from(category: String) -> Self {
+		match category.as_str() {
+			"weapon" => Category::Weapon,
+			"armor" => Category::Armor,
+			"accessories" => Category::Accessories,
+			"consumables" => Category::Consumables,
+			"blocks" => Category::Blocks,
+			"misc" => Category::Misc,
+			_ => Category::Unknown(category)
+		}
+	}
+}
+
+impl From<Category> for String {
+	fn from(category: Category) -> Self {
+		match category {
+			Category::Unknown(category) => category,
+			category => category.as_str().to_owned()
+		}
+	}
+}
+
+impl fmt::Display for Category {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// Upgrades applied to an item which move its value away from that of a plain copy.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Modifier {
+	Enchanted,
+	Recombobulated,
+	Starred,
+	HotPotatoBooks,
+	Reforged,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -40,7 +195,7 @@ pub struct Item {
 	/// it includes enchants and the literal minecraft item's name
 	pub extra: String,
 	/// The auction category of an item
-	pub category: String,
+	pub category: Category,
 	/// The rarity of the item auctioned
 	pub tier: Rarity,
 	/// The item's gzipped NBT representation
@@ -54,7 +209,7 @@ use nbt::from_gzip_reader;
 #[cfg(feature = "nbt")]
 use std::io;
 #[cfg(feature = "nbt")]
-use crate::objects::nbt::PartialNbt;
+use crate::objects::nbt::{PartialInventory, PartialNbt};
 
 impl Item {
 	/// Deflates the bytes into a partial NBT tag
@@ -82,8 +237,88 @@ impl Item {
 			}
 		}
 
-		return None;
+		None
+	}
+
+	/// Returns the lore without any minecraft colour or formatting codes.
+	pub fn plain_lore(&self) -> String {
+		strip_formatting(&self.lore)
 	}
+
+	/// Returns the modifiers applied to the item.
+	/// Recombobulators and stars are recognised from the lore and name,
+	/// the remaining modifiers can only be read from the NBT.
+	pub fn modifiers(&self) -> Vec<Modifier> {
+		let mut modifiers = vec![];
+
+		#[cfg(feature = "nbt")]
+		if let Ok(nbt) = self.to_nbt() {
+			if let Some(pnbt) = nbt.i.first() {
+				let attr = &pnbt.tag.extra_attributes;
+
+				if attr.enchantments.iter().any(|e| !e.is_empty()) {
+					modifiers.push(Modifier::Enchanted);
+				}
+				if attr.rarity_upgrades.unwrap_or(0) > 0 {
+					modifiers.push(Modifier::Recombobulated);
+				}
+				if attr.upgrade_level.or(attr.dungeon_item_level).unwrap_or(0) > 0 {
+					modifiers.push(Modifier::Starred);
+				}
+				if attr.hot_potato_count.unwrap_or(0) > 0 {
+					modifiers.push(Modifier::HotPotatoBooks);
+				}
+				if attr.modifier.is_some() {
+					modifiers.push(Modifier::Reforged);
+				}
+			}
+		}
+
+		// Recombobulated items have their rarity wrapped in obfuscated text.
+		if self.lore.contains("§ka") && !modifiers.contains(&Modifier::Recombobulated) {
+			modifiers.push(Modifier::Recombobulated);
+		}
+		if self.name.contains('✪') && !modifiers.contains(&Modifier::Starred) {
+			modifiers.push(Modifier::Starred);
+		}
+
+		modifiers
+	}
+
+	/// Returns a canonical key for grouping listings of the same item.
+	/// This is the Skyblock item ID (e.g. `HOT_POTATO_BOOK`) if it can be read from the NBT,
+	/// otherwise it is approximated by upper snake casing the name and dropping any symbols, such as stars.
+	/// The approximation does not strip reforges.
+	/// Pets all share an ID, so they're keyed by type and rarity instead, e.g. `PET_ENDER_DRAGON_LEGENDARY`.
+	pub fn key(&self) -> String {
+		#[cfg(feature = "nbt")]
+		if let Ok(nbt) = self.to_nbt() {
+			if let Some(pnbt) = nbt.i.first() {
+				let id = &pnbt.tag.extra_attributes.id;
+
+				if id == "PET" {
+					// Pets are named after their type, behind their level, e.g. "[Lvl 100] Ender Dragon".
+					let name = self.name.rsplit(']').next().unwrap_or(&self.name);
+					return format!("PET_{}_{}", upper_snake_case(name), self.tier.as_str());
+				}
+
+				return id.clone();
+			}
+		}
+
+		upper_snake_case(&self.name)
+	}
+}
+
+/// Upper snake cases a name, dropping any symbols.
+fn upper_snake_case(name: &str) -> String {
+	name
+		.split_whitespace()
+		.map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect::<String>())
+		.filter(|word| !word.is_empty())
+		.map(|word| word.to_uppercase())
+		.collect::<Vec<_>>()
+		.join("_")
 }
 
 // Ugly hack because hitting skyblock/auction and skyblock/auctions returns slightly different data.
@@ -95,6 +330,16 @@ pub enum ItemBytes {
 	Data(String),
 }
 
+#[cfg(feature = "nbt")]
+impl ItemBytes {
+	/// Deflates the bytes of a container, such as an inventory, into its slots.
+	pub fn to_inventory(&self) -> Result<PartialInventory> {
+		let bytes: StdResult<Vec<u8>, _> = self.clone().into();
+		let inventory: PartialInventory = from_gzip_reader(io::Cursor::new(bytes?))?;
+		Ok(inventory)
+	}
+}
+
 #[cfg(feature = "bytes")]
 impl Into<String> for ItemBytes {
 	fn into(self) -> String {
@@ -0,0 +1,112 @@
+use crate::objects::auction::time::from_millis;
+#[cfg(feature = "bytes")]
+use crate::objects::items::ItemBytes;
+#[cfg(feature = "nbt")]
+use crate::objects::nbt::PartialNbt;
+#[cfg(feature = "nbt")]
+use crate::Result;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// The museums of every member of a profile, from `skyblock/museum`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Museum {
+	/// The members' museums, keyed by player UUID
+	pub members: HashMap<String, MuseumMember>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct MuseumMember {
+	/// The total value of the donations, as last appraised in game
+	#[serde(default)]
+	pub value: i64,
+	/// Whether the member has unlocked appraisal of their museum
+	#[serde(default)]
+	pub appraisal: bool,
+	/// Donations of items and item sets, keyed by the museum's ID for them
+	#[serde(default)]
+	pub items: HashMap<String, Donation>,
+	/// Donations of special items, which may be donated more than once
+	#[serde(default)]
+	pub special: Vec<Donation>,
+}
+
+impl MuseumMember {
+	/// Every donation, most recent first.
+	pub fn donations(&self) -> Vec<&Donation> {
+		let mut donations: Vec<&Donation> = self.items.values().chain(self.special.iter()).collect();
+		donations.sort_by_key(|donation| std::cmp::Reverse(donation.donated_time));
+
+		donations
+	}
+
+	/// Decodes every donated item, most recently donated first.
+	#[cfg(feature = "nbt")]
+	pub fn donated_items(&self) -> Result<Vec<DonatedItem>> {
+		let mut items = vec![];
+
+		for donation in self.donations() {
+			items.extend(donation.items()?);
+		}
+
+		Ok(items)
+	}
+}
+
+/// An item or set of items donated to the museum.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Donation {
+	/// Unix time (in milliseconds) of when the items were donated
+	pub donated_time: i64,
+	/// The display slot the donation is featured in, if any
+	pub featured_slot: Option<String>,
+	/// Whether the member has taken the items back out to use
+	#[serde(default)]
+	pub borrowing: bool,
+	/// The donated items' gzipped NBT representation
+	#[cfg(feature = "bytes")]
+	#[serde(rename = "items")]
+	pub bytes: ItemBytes,
+}
+
+impl Donation {
+	pub fn donated_time(&self) -> SystemTime {
+		from_millis(self.donated_time)
+	}
+
+	/// Deflates the bytes into a partial NBT tag, holding an element per donated item.
+	#[cfg(feature = "nbt")]
+	pub fn to_nbt(&self) -> Result<PartialNbt> {
+		let bytes: Result<Vec<u8>> = self.bytes.clone().into();
+		let nbt: PartialNbt = nbt::from_gzip_reader(std::io::Cursor::new(bytes?))?;
+		Ok(nbt)
+	}
+
+	/// Decodes the donated items.
+	#[cfg(feature = "nbt")]
+	pub fn items(&self) -> Result<Vec<DonatedItem>> {
+		let nbt = self.to_nbt()?;
+
+		Ok(nbt.i.into_iter()
+			.map(|element| DonatedItem {
+				id: element.tag.extra_attributes.id,
+				name: element.tag.display.name,
+				count: element.count,
+				donated_time: self.donated_time,
+			})
+			.collect())
+	}
+}
+
+/// A single item decoded from a `Donation`.
+#[cfg(feature = "nbt")]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct DonatedItem {
+	/// The Skyblock item ID
+	pub id: String,
+	/// The name of the item, including minecraft colour codes
+	pub name: String,
+	pub count: i8,
+	/// Unix time (in milliseconds) of when the item was donated
+	pub donated_time: i64,
+}
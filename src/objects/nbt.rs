@@ -12,6 +12,20 @@ pub struct PartialNbtElement {
 	pub tag: PartialTag,
 }
 
+/// A container of items, such as an inventory, which may have empty slots.
+#[derive(Deserialize)]
+pub struct PartialInventory {
+	pub i: Vec<PartialSlot>,
+}
+
+/// A slot of a container, with every field missing when it's empty.
+#[derive(Deserialize)]
+pub struct PartialSlot {
+	#[serde(rename = "Count")]
+	pub count: Option<i8>,
+	pub tag: Option<PartialTag>,
+}
+
 #[derive(Deserialize)]
 pub struct PartialTag {
 	#[serde(rename = "ExtraAttributes")]
@@ -22,14 +36,26 @@ pub struct PartialTag {
 #[derive(Deserialize)]
 pub struct PartialExtraAttr {
 	pub id: String,
+	/// Identifies a single item, absent on stackable items
+	pub uuid: Option<String>,
 	pub enchantments: Option<HashMap<String, i32>>,
 	pub anvil_uses: Option<i8>,
+	/// The number of recombobulators applied to the item
+	pub rarity_upgrades: Option<i32>,
+	/// The number of hot potato (and fuming potato) books applied to the item
+	pub hot_potato_count: Option<i32>,
+	/// The number of stars on a dungeon item
+	pub dungeon_item_level: Option<i32>,
+	/// The number of stars on an item, superseding `dungeon_item_level`
+	pub upgrade_level: Option<i32>,
+	/// The reforge applied to the item
+	pub modifier: Option<String>,
 }
 
 #[derive(Deserialize)]
 pub struct DisplayInfo {
 	#[serde(rename = "Name")]
 	pub name: String,
-	#[serde(rename = "Lore")]
+	#[serde(rename = "Lore", default)]
 	pub lore: Vec<String>,
 }
\ No newline at end of file
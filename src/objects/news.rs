@@ -0,0 +1,21 @@
+/// The latest SkyBlock news, from `skyblock/news`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct News {
+	pub items: Vec<NewsItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct NewsItem {
+	pub title: String,
+	/// A short summary, usually the date of the update
+	pub text: String,
+	/// The forum thread for the update
+	pub link: String,
+	/// The item shown as the icon of the news entry
+	pub item: NewsIcon,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct NewsIcon {
+	pub material: String,
+}
@@ -0,0 +1,156 @@
+use crate::objects::auction::index::AuctionIndex;
+use crate::objects::bazaar::Product;
+use crate::objects::resources::ItemsResource;
+use std::collections::HashMap;
+
+/// Weekly bazaar volume at which a product's prices are trusted halfway.
+const BAZAAR_VOLUME_HALF_CONFIDENCE: f64 = 10_000.0;
+
+/// BIN listings at which an item's lowest BIN is trusted halfway.
+const BIN_LISTINGS_HALF_CONFIDENCE: f64 = 4.0;
+
+/// Something that knows what items are worth, keyed by Skyblock item ID,
+/// regardless of whether they're traded on the bazaar, the auction house or elsewhere.
+pub trait PriceSource {
+	/// The coins it takes to get hold of one of the item, `None` if it can't be bought.
+	fn buy_price(&self, id: &str) -> Option<f64>;
+
+	/// The coins one of the item can be sold for, `None` if it can't be sold.
+	fn sell_price(&self, id: &str) -> Option<f64>;
+
+	/// How far the prices of the item can be trusted, from zero to one.
+	/// Thinly traded items have a low confidence, and items that can't be priced have none.
+	fn confidence(&self, id: &str) -> f64;
+}
+
+/// Prices products at what they can be instantly bought and sold for,
+/// falling back on the weighted prices when a side of the book is empty.
+/// Takes the listing from `get_bazaar_product_listing`.
+impl PriceSource for HashMap<String, Product> {
+	fn buy_price(&self, id: &str) -> Option<f64> {
+		let product = self.get(id)?;
+
+		product.live_data.best_ask()
+			.or_else(|| Some(product.live_data.quick_status.buy_price as f64).filter(|price| *price > 0.0))
+	}
+
+	fn sell_price(&self, id: &str) -> Option<f64> {
+		let product = self.get(id)?;
+
+		product.live_data.best_bid()
+			.or_else(|| Some(product.live_data.quick_status.sell_price as f64).filter(|price| *price > 0.0))
+	}
+
+	/// Grows with the weekly volume of the product's quieter side.
+	fn confidence(&self, id: &str) -> f64 {
+		let status = match self.get(id) {
+			Some(product) => &product.live_data.quick_status,
+			None => return 0.0,
+		};
+		let volume = status.buy_moving_week.min(status.sell_moving_week).max(0.0) as f64;
+
+		volume / (volume + BAZAAR_VOLUME_HALF_CONFIDENCE)
+	}
+}
+
+/// Prices items at their lowest BIN listing, both ways, as a listing has to match it to sell.
+impl PriceSource for AuctionIndex {
+	fn buy_price(&self, id: &str) -> Option<f64> {
+		self.lowest_bin(id).map(|price| price as f64)
+	}
+
+	fn sell_price(&self, id: &str) -> Option<f64> {
+		self.lowest_bin(id).map(|price| price as f64)
+	}
+
+	/// Grows with the number of BIN listings of the item.
+	fn confidence(&self, id: &str) -> f64 {
+		let listings = self.get(id).map_or(0, |entry| entry.bin_listings) as f64;
+
+		listings / (listings + BIN_LISTINGS_HALF_CONFIDENCE)
+	}
+}
+
+/// Prices items at what NPCs pay for them. Items can't be bought this way.
+impl PriceSource for ItemsResource {
+	fn buy_price(&self, _: &str) -> Option<f64> {
+		None
+	}
+
+	fn sell_price(&self, id: &str) -> Option<f64> {
+		self.npc_sell_price(id)
+	}
+
+	/// NPCs always pay the same, so their prices are certain.
+	fn confidence(&self, id: &str) -> f64 {
+		match self.npc_sell_price(id) {
+			Some(_) => 1.0,
+			None => 0.0
+		}
+	}
+}
+
+impl<T: PriceSource + ?Sized> PriceSource for &T {
+	fn buy_price(&self, id: &str) -> Option<f64> {
+		(**self).buy_price(id)
+	}
+
+	fn sell_price(&self, id: &str) -> Option<f64> {
+		(**self).sell_price(id)
+	}
+
+	fn confidence(&self, id: &str) -> f64 {
+		(**self).confidence(id)
+	}
+}
+
+/// A chain of price sources, each price coming from the first source that can provide it,
+/// e.g. the bazaar, then the lowest BINs, then NPC prices.
+#[derive(Default)]
+pub struct CompositePrices<'a> {
+	sources: Vec<Box<dyn PriceSource + 'a>>,
+	min_confidence: f64,
+}
+
+impl<'a> CompositePrices<'a> {
+	pub fn new() -> CompositePrices<'a> {
+		Self::default()
+	}
+
+	/// Adds a source to fall back on, after those already in the chain.
+	pub fn then<P: PriceSource + 'a>(mut self, source: P) -> CompositePrices<'a> {
+		self.sources.push(Box::new(source));
+		self
+	}
+
+	/// Skips sources whose confidence in an item falls below `confidence`.
+	pub fn min_confidence(mut self, confidence: f64) -> CompositePrices<'a> {
+		self.min_confidence = confidence;
+		self
+	}
+
+	fn first<F>(&self, id: &str, price: F) -> Option<(f64, &dyn PriceSource)> where
+		F: Fn(&dyn PriceSource) -> Option<f64> {
+		self.sources.iter()
+			.map(|source| source.as_ref())
+			.filter(|source| source.confidence(id) >= self.min_confidence)
+			.find_map(|source| price(source).map(|price| (price, source)))
+	}
+}
+
+impl PriceSource for CompositePrices<'_> {
+	fn buy_price(&self, id: &str) -> Option<f64> {
+		self.first(id, |source| source.buy_price(id)).map(|(price, _)| price)
+	}
+
+	fn sell_price(&self, id: &str) -> Option<f64> {
+		self.first(id, |source| source.sell_price(id)).map(|(price, _)| price)
+	}
+
+	/// The confidence of the source the sell price comes from, otherwise that of the buy price.
+	fn confidence(&self, id: &str) -> f64 {
+		self.first(id, |source| source.sell_price(id))
+			.or_else(|| self.first(id, |source| source.buy_price(id)))
+			.map_or(0.0, |(_, source)| source.confidence(id))
+	}
+}
@@ -1,3 +1,214 @@
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub mod accessories;
+pub mod networth;
+pub mod progression;
+pub mod snapshot;
+
+#[cfg(feature = "bytes")]
+use crate::objects::items::ItemBytes;
+use crate::objects::items::Rarity;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 #[serde(transparent)]
 pub struct PartialProfile(pub String);
+
+/// The ID of a SkyBlock profile, as opposed to the UUID of a player on it.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
+#[serde(transparent)]
+pub struct ProfileId(pub String);
+
+/// A SkyBlock profile, from `skyblock/profile` or `skyblock/profiles`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Profile {
+	pub profile_id: ProfileId,
+	/// The fruit the profile is named after, e.g. `Apple`
+	pub cute_name: Option<String>,
+	/// Whether this is the player's selected profile, only set by `skyblock/profiles`
+	#[serde(default)]
+	pub selected: bool,
+	/// The members of the profile, keyed by player UUID
+	pub members: HashMap<String, ProfileMember>,
+	/// The profile's bank, absent if the banking API setting is disabled
+	pub banking: Option<Banking>,
+}
+
+impl Profile {
+	pub fn member(&self, uuid: &str) -> Option<&ProfileMember> {
+		self.members.get(uuid)
+	}
+}
+
+/// A player's progress on a profile.
+/// Sections of the profile are missing when the player has disabled the matching API setting.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ProfileMember {
+	#[serde(default)]
+	pub player_data: PlayerData,
+	#[serde(default)]
+	pub slayer: SlayerData,
+	#[serde(default)]
+	pub dungeons: DungeonsData,
+	#[serde(default)]
+	pub currencies: Currencies,
+	#[serde(default)]
+	pub inventory: Inventories,
+	#[serde(default)]
+	pub pets_data: PetsData,
+	/// The amount collected of each collection, keyed by item ID
+	#[serde(default)]
+	pub collection: HashMap<String, i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct PlayerData {
+	/// The XP of each skill, keyed by skill, e.g. `SKILL_FARMING`
+	#[serde(default)]
+	pub experience: HashMap<String, f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SlayerData {
+	/// Keyed by boss, e.g. `zombie`
+	#[serde(default)]
+	pub slayer_bosses: HashMap<String, SlayerBoss>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SlayerBoss {
+	#[serde(default)]
+	pub xp: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct DungeonsData {
+	/// Keyed by dungeon, e.g. `catacombs`
+	#[serde(default)]
+	pub dungeon_types: HashMap<String, DungeonStats>,
+	/// Keyed by class, e.g. `healer`
+	#[serde(default)]
+	pub player_classes: HashMap<String, DungeonStats>,
+	pub selected_dungeon_class: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct DungeonStats {
+	#[serde(default)]
+	pub experience: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Banking {
+	#[serde(default)]
+	pub balance: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Currencies {
+	/// The coins carried by the member
+	#[serde(default)]
+	pub coin_purse: f64,
+}
+
+/// The member's containers, each holding gzipped NBT like `Item::bytes`.
+/// Absent containers are either empty or hidden by the inventory API setting.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Inventories {
+	#[cfg(feature = "bytes")]
+	pub inv_contents: Option<ItemBytes>,
+	#[cfg(feature = "bytes")]
+	pub inv_armor: Option<ItemBytes>,
+	#[cfg(feature = "bytes")]
+	pub equipment_contents: Option<ItemBytes>,
+	#[cfg(feature = "bytes")]
+	pub wardrobe_contents: Option<ItemBytes>,
+	#[cfg(feature = "bytes")]
+	pub ender_chest_contents: Option<ItemBytes>,
+	#[cfg(feature = "bytes")]
+	pub personal_vault_contents: Option<ItemBytes>,
+	/// Keyed by backpack slot
+	#[cfg(feature = "bytes")]
+	#[serde(default)]
+	pub backpack_contents: HashMap<String, ItemBytes>,
+	/// Keyed by bag, e.g. `talisman_bag`
+	#[cfg(feature = "bytes")]
+	#[serde(default)]
+	pub bag_contents: HashMap<String, ItemBytes>,
+	/// The items in the member's sacks, keyed by item ID
+	#[serde(default)]
+	pub sacks_counts: HashMap<String, i64>,
+}
+
+#[cfg(feature = "bytes")]
+impl Inventories {
+	/// Every container which isn't absent, backpacks and bags in order of their keys.
+	pub fn containers(&self) -> Vec<(Container, &ItemBytes)> {
+		let mut containers: Vec<(Container, &ItemBytes)> = [
+			(Container::Inventory, &self.inv_contents),
+			(Container::Armor, &self.inv_armor),
+			(Container::Equipment, &self.equipment_contents),
+			(Container::Wardrobe, &self.wardrobe_contents),
+			(Container::EnderChest, &self.ender_chest_contents),
+			(Container::PersonalVault, &self.personal_vault_contents),
+		].iter()
+			.filter_map(|(container, bytes)| bytes.as_ref().map(|bytes| (container.clone(), bytes)))
+			.collect();
+
+		let mut backpacks: Vec<_> = self.backpack_contents.iter().collect();
+		backpacks.sort_by_key(|(slot, _)| slot.parse::<i32>().unwrap_or(i32::MAX));
+		containers.extend(backpacks.into_iter().map(|(slot, bytes)| (Container::Backpack(slot.clone()), bytes)));
+
+		let mut bags: Vec<_> = self.bag_contents.iter().collect();
+		bags.sort_by_key(|(bag, _)| bag.as_str());
+		containers.extend(bags.into_iter().map(|(bag, bytes)| (Container::Bag(bag.clone()), bytes)));
+
+		containers
+	}
+}
+
+/// One of a member's containers of items.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+pub enum Container {
+	Inventory,
+	Armor,
+	Equipment,
+	Wardrobe,
+	EnderChest,
+	PersonalVault,
+	/// A backpack, by its slot
+	Backpack(String),
+	/// A bag, by its key, e.g. `talisman_bag`
+	Bag(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct PetsData {
+	#[serde(default)]
+	pub pets: Vec<Pet>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Pet {
+	pub uuid: Option<String>,
+	/// The kind of pet, e.g. `ENDER_DRAGON`
+	#[serde(rename = "type")]
+	pub pet_type: String,
+	#[serde(rename = "exp", default)]
+	pub xp: f64,
+	#[serde(default)]
+	pub active: bool,
+	pub tier: Rarity,
+	/// The ID of the item the pet is holding
+	#[serde(rename = "heldItem")]
+	pub held_item: Option<String>,
+	#[serde(rename = "candyUsed", default)]
+	pub candy_used: i32,
+	/// The skin applied to the pet, e.g. `ENDER_DRAGON_NEON` for the `PET_SKIN_ENDER_DRAGON_NEON` item
+	pub skin: Option<String>,
+}
+
+impl Pet {
+	/// The key the pet is priced under, matching `Item::key` for pets, e.g. `PET_ENDER_DRAGON_LEGENDARY`.
+	pub fn key(&self) -> String {
+		format!("PET_{}_{}", self.pet_type, self.tier.as_str())
+	}
+}
@@ -0,0 +1,313 @@
+use crate::objects::items::Rarity;
+#[cfg(feature = "nbt")]
+use crate::objects::items::strip_formatting;
+use crate::objects::pricing::PriceSource;
+#[cfg(feature = "nbt")]
+use crate::objects::profile::ProfileMember;
+use crate::objects::resources::{ItemsResource, SkyblockItem};
+#[cfg(feature = "nbt")]
+use crate::Result;
+use std::collections::{BTreeMap, HashMap};
+
+/// The category of accessories in the items resource.
+const ACCESSORY: &str = "ACCESSORY";
+
+/// The artifact which counts its magical power twice.
+const HEGEMONY_ARTIFACT: &str = "HEGEMONY_ARTIFACT";
+
+/// Accessories which upgrade into one another, lowest first.
+const UPGRADE_LINES: [&[&str]; 24] = [
+	&["SPEED_TALISMAN", "SPEED_RING", "SPEED_ARTIFACT"],
+	&["FEATHER_TALISMAN", "FEATHER_RING", "FEATHER_ARTIFACT"],
+	&["INTIMIDATION_TALISMAN", "INTIMIDATION_RING", "INTIMIDATION_ARTIFACT"],
+	&["CANDY_TALISMAN", "CANDY_RING", "CANDY_ARTIFACT", "CANDY_RELIC"],
+	&["POTION_AFFINITY_TALISMAN", "RING_POTION_AFFINITY", "ARTIFACT_POTION_AFFINITY"],
+	&["SEA_CREATURE_TALISMAN", "SEA_CREATURE_RING", "SEA_CREATURE_ARTIFACT"],
+	&["ZOMBIE_TALISMAN", "ZOMBIE_RING", "ZOMBIE_ARTIFACT"],
+	&["SPIDER_TALISMAN", "SPIDER_RING", "SPIDER_ARTIFACT"],
+	&["RED_CLAW_TALISMAN", "RED_CLAW_RING", "RED_CLAW_ARTIFACT"],
+	&["BAT_TALISMAN", "BAT_RING", "BAT_ARTIFACT"],
+	&["BAT_PERSON_TALISMAN", "BAT_PERSON_RING", "BAT_PERSON_ARTIFACT"],
+	&["TREASURE_TALISMAN", "TREASURE_RING", "TREASURE_ARTIFACT"],
+	&["WOLF_TALISMAN", "WOLF_RING"],
+	&["HEALING_TALISMAN", "HEALING_RING"],
+	&["HUNTER_TALISMAN", "HUNTER_RING"],
+	&["SHADY_RING", "CROOKED_ARTIFACT", "SEAL_OF_THE_FAMILY"],
+	&["WITHER_ARTIFACT", "WITHER_RELIC"],
+	&["SCARF_STUDIES", "SCARF_THESIS", "SCARF_GRIMOIRE"],
+	&["PERSONAL_COMPACTOR_4000", "PERSONAL_COMPACTOR_5000", "PERSONAL_COMPACTOR_6000", "PERSONAL_COMPACTOR_7000"],
+	&["PERSONAL_DELETOR_4000", "PERSONAL_DELETOR_5000", "PERSONAL_DELETOR_6000", "PERSONAL_DELETOR_7000"],
+	&["TITANIUM_TALISMAN", "TITANIUM_RING", "TITANIUM_ARTIFACT", "TITANIUM_RELIC"],
+	&["DRACONIC_TALISMAN", "DRACONIC_RING", "DRACONIC_ARTIFACT"],
+	&["BURSTSTOPPER_TALISMAN", "BURSTSTOPPER_ARTIFACT"],
+	&["PIGGY_BANK", "CRACKED_PIGGY_BANK", "BROKEN_PIGGY_BANK"],
+];
+
+/// The magical power an accessory of a rarity grants.
+pub fn magical_power(rarity: Rarity) -> i32 {
+	match rarity {
+		Rarity::Common | Rarity::Special => 3,
+		Rarity::Uncommon | Rarity::VerySpecial => 5,
+		Rarity::Rare => 8,
+		Rarity::Epic => 12,
+		Rarity::Legendary => 16,
+		Rarity::Mythic | Rarity::Artifact | Rarity::Divine | Rarity::Supreme => 22,
+		Rarity::Unobtainable => 0,
+	}
+}
+
+/// An accessory in the bag, at its current rarity, including any recombobulator.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Accessory {
+	pub id: String,
+	pub rarity: Rarity,
+}
+
+impl Accessory {
+	pub fn new(id: &str, rarity: Rarity) -> Accessory {
+		Accessory { id: id.to_owned(), rarity }
+	}
+
+	/// The magical power the accessory grants, if it counts.
+	pub fn magical_power(&self) -> i32 {
+		let power = magical_power(self.rarity);
+
+		match self.id.as_str() {
+			HEGEMONY_ARTIFACT => power * 2,
+			_ => power
+		}
+	}
+}
+
+/// The lines of accessories which upgrade into one another.
+/// Only the highest accessory of a line counts towards magical power.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[serde(transparent)]
+pub struct UpgradeLines {
+	lines: Vec<Vec<String>>,
+}
+
+impl UpgradeLines {
+	pub fn new() -> UpgradeLines {
+		Self::default()
+	}
+
+	/// The bundled lines, covering the common talisman, ring and artifact upgrades.
+	pub fn bundled() -> UpgradeLines {
+		let mut lines = Self::new();
+
+		for line in UPGRADE_LINES.iter() {
+			lines.push(line.iter().map(|id| id.to_string()).collect());
+		}
+
+		lines
+	}
+
+	/// Adds a line of accessories, lowest first.
+	pub fn push(&mut self, line: Vec<String>) {
+		self.lines.push(line);
+	}
+
+	/// The line an accessory belongs to, and its position in it.
+	pub fn position(&self, id: &str) -> Option<(usize, usize)> {
+		self.lines.iter()
+			.enumerate()
+			.find_map(|(line, ids)| ids.iter().position(|other| other == id).map(|tier| (line, tier)))
+	}
+}
+
+/// Why an accessory doesn't count towards magical power.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub enum Exclusion {
+	/// Another copy of the accessory is in the bag
+	Duplicate,
+	/// The bag holds an upgrade of the accessory, with this ID
+	Superseded(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ExcludedAccessory {
+	pub accessory: Accessory,
+	pub reason: Exclusion,
+}
+
+/// An accessory to get next, and what it would add.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Suggestion {
+	pub id: String,
+	pub rarity: Rarity,
+	/// The magical power gained, net of any lower accessory of the line it replaces
+	pub magical_power: i32,
+	pub cost: f64,
+	pub cost_per_power: f64,
+}
+
+/// The make-up of an accessory bag's magical power.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct AccessoryAnalysis {
+	/// The accessories counting towards magical power
+	pub counted: Vec<Accessory>,
+	pub excluded: Vec<ExcludedAccessory>,
+	pub magical_power: i32,
+	/// The magical power granted by the counted accessories of each rarity
+	pub by_rarity: BTreeMap<Rarity, i32>,
+}
+
+impl AccessoryAnalysis {
+	pub fn analyse(accessories: Vec<Accessory>, lines: &UpgradeLines) -> AccessoryAnalysis {
+		let mut analysis = AccessoryAnalysis::default();
+
+		// The best copy of each accessory, then the highest accessory of each line, is kept.
+		let mut best: HashMap<String, Accessory> = HashMap::new();
+		for accessory in accessories {
+			let duplicate = match best.get(&accessory.id) {
+				Some(kept) if kept.magical_power() >= accessory.magical_power() => accessory,
+				_ => match best.insert(accessory.id.clone(), accessory) {
+					Some(replaced) => replaced,
+					None => continue,
+				}
+			};
+
+			analysis.excluded.push(ExcludedAccessory {
+				accessory: duplicate,
+				reason: Exclusion::Duplicate,
+			});
+		}
+
+		let mut highest: HashMap<usize, (usize, String)> = HashMap::new();
+		for id in best.keys() {
+			if let Some((line, tier)) = lines.position(id) {
+				let entry = highest.entry(line).or_insert((tier, id.clone()));
+				if tier > entry.0 {
+					*entry = (tier, id.clone());
+				}
+			}
+		}
+
+		let mut kept: Vec<Accessory> = best.into_values().collect();
+		kept.sort_by(|a, b| a.id.cmp(&b.id));
+
+		for accessory in kept {
+			let superseded_by = lines.position(&accessory.id)
+				.map(|(line, _)| &highest[&line].1)
+				.filter(|top| **top != accessory.id);
+
+			match superseded_by {
+				Some(top) => analysis.excluded.push(ExcludedAccessory {
+					reason: Exclusion::Superseded(top.clone()),
+					accessory,
+				}),
+				None => {
+					analysis.magical_power += accessory.magical_power();
+					*analysis.by_rarity.entry(accessory.rarity).or_default() += accessory.magical_power();
+					analysis.counted.push(accessory);
+				}
+			}
+		}
+
+		analysis
+	}
+
+	/// Whether the bag holds the accessory, or an upgrade of it.
+	pub fn has(&self, id: &str, lines: &UpgradeLines) -> bool {
+		let position = lines.position(id);
+
+		self.counted.iter().any(|accessory| match (position, lines.position(&accessory.id)) {
+			(Some((line, tier)), Some((other_line, other_tier))) => line == other_line && tier <= other_tier,
+			_ => accessory.id == id,
+		})
+	}
+
+	/// The magical power of the counted accessory which getting `id` would replace, if any.
+	fn replaced_power(&self, id: &str, lines: &UpgradeLines) -> i32 {
+		let line = match lines.position(id) {
+			Some((line, _)) => line,
+			None => return 0,
+		};
+
+		self.counted.iter()
+			.find(|accessory| matches!(lines.position(&accessory.id), Some((other, _)) if other == line))
+			.map_or(0, Accessory::magical_power)
+	}
+
+	/// The accessories in the items resource which the bag has neither the accessory nor an upgrade of.
+	pub fn missing<'a>(&self, items: &'a ItemsResource, lines: &UpgradeLines) -> Vec<&'a SkyblockItem> {
+		let mut missing: Vec<&SkyblockItem> = items.items.values()
+			.filter(|item| item.category.as_deref() == Some(ACCESSORY))
+			.filter(|item| !self.has(&item.id, lines))
+			.collect();
+		missing.sort_by(|a, b| a.id.cmp(&b.id));
+
+		missing
+	}
+
+	/// The missing accessories that can be bought, cheapest per magical power gained first.
+	pub fn suggestions<P: PriceSource + ?Sized>(&self, items: &ItemsResource, lines: &UpgradeLines, prices: &P) -> Vec<Suggestion> {
+		let mut suggestions: Vec<Suggestion> = self.missing(items, lines).into_iter()
+			.filter_map(|item| {
+				let rarity = item.tier?;
+				let gained = Accessory::new(&item.id, rarity).magical_power() - self.replaced_power(&item.id, lines);
+				if gained <= 0 {
+					return None;
+				}
+
+				let cost = prices.buy_price(&item.id)?;
+				Some(Suggestion {
+					id: item.id.clone(),
+					rarity,
+					magical_power: gained,
+					cost,
+					cost_per_power: cost / gained as f64,
+				})
+			})
+			.collect();
+		suggestions.sort_by(|a, b| a.cost_per_power.partial_cmp(&b.cost_per_power).unwrap_or(std::cmp::Ordering::Equal));
+
+		suggestions
+	}
+}
+
+/// Reads the rarity from the last line of an item's lore, e.g. "§6§lLEGENDARY ACCESSORY".
+/// Recombobulated items have obfuscated characters around the rarity, which are skipped over.
+#[cfg(feature = "nbt")]
+fn lore_rarity(lore: &[String]) -> Option<Rarity> {
+	let line = lore.iter().rev().find(|line| !line.trim().is_empty())?;
+	let plain = strip_formatting(line);
+
+	if plain.contains("VERY SPECIAL") {
+		return Some(Rarity::VerySpecial);
+	}
+
+	plain.split_whitespace().find_map(|word| match word {
+		"COMMON" => Some(Rarity::Common),
+		"UNCOMMON" => Some(Rarity::Uncommon),
+		"RARE" => Some(Rarity::Rare),
+		"EPIC" => Some(Rarity::Epic),
+		"LEGENDARY" => Some(Rarity::Legendary),
+		"MYTHIC" => Some(Rarity::Mythic),
+		"DIVINE" => Some(Rarity::Divine),
+		"SPECIAL" => Some(Rarity::Special),
+		"SUPREME" => Some(Rarity::Supreme),
+		_ => None
+	})
+}
+
+#[cfg(feature = "nbt")]
+impl ProfileMember {
+	/// Decodes the accessory bag, leaving out any accessory whose rarity can't be read from its lore.
+	/// Empty if the bag is hidden by the inventory API setting.
+	pub fn accessories(&self) -> Result<Vec<Accessory>> {
+		let bag = match self.inventory.bag_contents.get("talisman_bag") {
+			Some(bag) => bag.to_inventory()?,
+			None => return Ok(vec![]),
+		};
+
+		Ok(bag.i.iter()
+			.filter_map(|slot| slot.tag.as_ref())
+			.filter_map(|tag| Some(Accessory {
+				id: tag.extra_attributes.id.clone(),
+				rarity: lore_rarity(&tag.display.lore)?,
+			}))
+			.collect())
+	}
+}
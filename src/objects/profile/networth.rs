@@ -0,0 +1,312 @@
+#[cfg(feature = "nbt")]
+use crate::objects::items::ItemBytes;
+#[cfg(feature = "nbt")]
+use crate::objects::nbt::PartialTag;
+#[cfg(feature = "nbt")]
+use crate::objects::profile::Container;
+use crate::objects::pricing::PriceSource;
+use crate::objects::profile::{Pet, Profile, ProfileMember};
+use crate::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Hot potato books past this many are fuming potato books.
+#[cfg(feature = "nbt")]
+const HOT_POTATO_BOOKS: i32 = 10;
+
+/// The items applying the sixth to tenth stars of a dungeon item.
+#[cfg(feature = "nbt")]
+const MASTER_STARS: [&str; 5] = [
+	"FIRST_MASTER_STAR", "SECOND_MASTER_STAR", "THIRD_MASTER_STAR", "FOURTH_MASTER_STAR", "FIFTH_MASTER_STAR",
+];
+
+/// Where a part of a member's networth is held.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum NetworthCategory {
+	Purse,
+	Bank,
+	Inventory,
+	Armor,
+	Equipment,
+	Wardrobe,
+	EnderChest,
+	PersonalVault,
+	Backpacks,
+	Accessories,
+	/// Every bag other than the accessory bag
+	Bags,
+	Pets,
+	Sacks,
+}
+
+/// How much of the price of what's been applied to an item is counted towards its value.
+/// Upgrades rarely sell for what they cost to apply, so most are discounted by default.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct Valuation {
+	pub enchantments: f64,
+	pub recombobulator: f64,
+	pub hot_potato_books: f64,
+	pub fuming_potato_books: f64,
+	pub master_stars: f64,
+	/// Items held by pets
+	pub pet_items: f64,
+	pub pet_skins: f64,
+}
+
+impl Default for Valuation {
+	fn default() -> Self {
+		Valuation {
+			enchantments: 0.85,
+			recombobulator: 0.8,
+			hot_potato_books: 1.0,
+			fuming_potato_books: 0.6,
+			master_stars: 1.0,
+			pet_items: 1.0,
+			pet_skins: 0.9,
+		}
+	}
+}
+
+/// Something applied to an item which adds to its value.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub enum Upgrade {
+	/// An enchantment and its level
+	Enchantment(String, i32),
+	Recombobulator,
+	HotPotatoBooks(i32),
+	FumingPotatoBooks(i32),
+	/// The number of master stars, past the five stars bought with essence
+	MasterStars(i32),
+	/// The ID of the item held by a pet
+	HeldItem(String),
+	/// The ID of the skin applied to a pet
+	Skin(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct UpgradeValue {
+	pub upgrade: Upgrade,
+	/// The weighted value of the upgrade
+	pub value: f64,
+}
+
+/// An item, or stack of items, and what it's worth.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ValuedItem {
+	/// The key the item is priced under
+	pub id: String,
+	/// The name of the item, including minecraft colour codes
+	pub name: String,
+	pub count: i64,
+	/// The price of one of the item, zero if it couldn't be priced
+	pub base: f64,
+	pub upgrades: Vec<UpgradeValue>,
+	/// The value of the stack and its upgrades
+	pub total: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct CategoryValue {
+	pub total: f64,
+	/// The valued items, most valuable first; empty for coins
+	pub items: Vec<ValuedItem>,
+}
+
+/// A breakdown of what a member is worth.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Networth {
+	pub total: f64,
+	pub categories: BTreeMap<NetworthCategory, CategoryValue>,
+	/// The keys of the items and upgrades the price source couldn't price
+	pub unpriced: BTreeSet<String>,
+}
+
+impl Networth {
+	/// The value held in a category, zero if nothing is held there.
+	pub fn category(&self, category: NetworthCategory) -> f64 {
+		self.categories.get(&category).map_or(0.0, |value| value.total)
+	}
+
+	fn add_coins(&mut self, category: NetworthCategory, coins: f64) {
+		self.categories.entry(category).or_default().total += coins;
+		self.total += coins;
+	}
+
+	fn add_item(&mut self, category: NetworthCategory, item: ValuedItem) {
+		let value = self.categories.entry(category).or_default();
+		value.total += item.total;
+		self.total += item.total;
+		value.items.push(item);
+	}
+
+	fn sort(&mut self) {
+		for value in self.categories.values_mut() {
+			value.items.sort_by(|a, b| b.total.partial_cmp(&a.total).unwrap_or(std::cmp::Ordering::Equal));
+		}
+	}
+}
+
+/// Looks up prices on behalf of a valuation, noting down what couldn't be priced.
+struct Pricer<'a, P: ?Sized> {
+	prices: &'a P,
+	valuation: &'a Valuation,
+	unpriced: BTreeSet<String>,
+}
+
+impl<'a, P: PriceSource + ?Sized> Pricer<'a, P> {
+	fn price(&mut self, id: &str) -> Option<f64> {
+		let price = self.prices.sell_price(id);
+		if price.is_none() {
+			self.unpriced.insert(id.to_owned());
+		}
+
+		price
+	}
+
+	fn upgrade(&mut self, upgrades: &mut Vec<UpgradeValue>, upgrade: Upgrade, id: &str, count: i32, weight: f64) {
+		if count <= 0 {
+			return;
+		}
+
+		if let Some(price) = self.price(id) {
+			upgrades.push(UpgradeValue {
+				upgrade,
+				value: price * count as f64 * weight,
+			});
+		}
+	}
+
+	fn item(&mut self, id: String, name: String, count: i64, upgrades: Vec<UpgradeValue>) -> ValuedItem {
+		let base = self.price(&id).unwrap_or(0.0);
+		let total = base * count as f64 + upgrades.iter().map(|upgrade| upgrade.value).sum::<f64>();
+
+		ValuedItem { id, name, count, base, upgrades, total }
+	}
+
+	#[cfg(feature = "nbt")]
+	fn tag(&mut self, tag: &PartialTag, count: i64) -> ValuedItem {
+		let attr = &tag.extra_attributes;
+		let weights = *self.valuation;
+		let mut upgrades = vec![];
+
+		let mut enchantments: Vec<_> = attr.enchantments.iter().flatten().collect();
+		enchantments.sort();
+		for (name, level) in enchantments {
+			let id = format!("ENCHANTMENT_{}_{}", name.to_uppercase(), level);
+			self.upgrade(&mut upgrades, Upgrade::Enchantment(name.clone(), *level), &id, 1, weights.enchantments);
+		}
+
+		if attr.rarity_upgrades.unwrap_or(0) > 0 {
+			self.upgrade(&mut upgrades, Upgrade::Recombobulator, "RECOMBOBULATOR_3000", 1, weights.recombobulator);
+		}
+
+		let books = attr.hot_potato_count.unwrap_or(0);
+		let hot = books.min(HOT_POTATO_BOOKS);
+		let fuming = books - hot;
+		self.upgrade(&mut upgrades, Upgrade::HotPotatoBooks(hot), "HOT_POTATO_BOOK", hot, weights.hot_potato_books);
+		self.upgrade(&mut upgrades, Upgrade::FumingPotatoBooks(fuming), "FUMING_POTATO_BOOK", fuming, weights.fuming_potato_books);
+
+		let master_stars = (attr.upgrade_level.or(attr.dungeon_item_level).unwrap_or(0) - 5).clamp(0, 5);
+		if master_stars > 0 {
+			let value: f64 = MASTER_STARS.iter()
+				.take(master_stars as usize)
+				.filter_map(|star| self.price(star))
+				.sum();
+
+			upgrades.push(UpgradeValue {
+				upgrade: Upgrade::MasterStars(master_stars),
+				value: value * weights.master_stars,
+			});
+		}
+
+		self.item(attr.id.clone(), tag.display.name.clone(), count, upgrades)
+	}
+
+	fn pet(&mut self, pet: &Pet) -> ValuedItem {
+		let weights = *self.valuation;
+		let mut upgrades = vec![];
+
+		if let Some(item) = &pet.held_item {
+			self.upgrade(&mut upgrades, Upgrade::HeldItem(item.clone()), item, 1, weights.pet_items);
+		}
+		if let Some(skin) = &pet.skin {
+			let id = format!("PET_SKIN_{}", skin);
+			self.upgrade(&mut upgrades, Upgrade::Skin(id.clone()), &id, 1, weights.pet_skins);
+		}
+
+		self.item(pet.key(), pet.pet_type.clone(), 1, upgrades)
+	}
+}
+
+/// Values every item in a container, skipping empty slots.
+#[cfg(feature = "nbt")]
+fn add_container<P: PriceSource + ?Sized>(networth: &mut Networth, pricer: &mut Pricer<P>, category: NetworthCategory, bytes: &ItemBytes) -> Result<()> {
+	for slot in bytes.to_inventory()?.i {
+		if let Some(tag) = &slot.tag {
+			let item = pricer.tag(tag, slot.count.unwrap_or(1) as i64);
+			networth.add_item(category, item);
+		}
+	}
+
+	Ok(())
+}
+
+impl ProfileMember {
+	/// Works out what the member is worth at the prices items can be sold for,
+	/// leaving out the bank which is shared by the profile.
+	/// Items can only be valued with the `nbt` feature; pets carried as items are left unpriced,
+	/// as are stars bought with essence, since their cost depends on the item.
+	pub fn networth<P: PriceSource + ?Sized>(&self, prices: &P, valuation: &Valuation) -> Result<Networth> {
+		let mut networth = Networth::default();
+		let mut pricer = Pricer { prices, valuation, unpriced: BTreeSet::new() };
+
+		networth.add_coins(NetworthCategory::Purse, self.currencies.coin_purse);
+
+		#[cfg(feature = "nbt")]
+		for (container, bytes) in self.inventory.containers() {
+			let category = match container {
+				Container::Inventory => NetworthCategory::Inventory,
+				Container::Armor => NetworthCategory::Armor,
+				Container::Equipment => NetworthCategory::Equipment,
+				Container::Wardrobe => NetworthCategory::Wardrobe,
+				Container::EnderChest => NetworthCategory::EnderChest,
+				Container::PersonalVault => NetworthCategory::PersonalVault,
+				Container::Backpack(_) => NetworthCategory::Backpacks,
+				Container::Bag(bag) if bag == "talisman_bag" => NetworthCategory::Accessories,
+				Container::Bag(_) => NetworthCategory::Bags,
+			};
+			add_container(&mut networth, &mut pricer, category, bytes)?;
+		}
+
+		for pet in &self.pets_data.pets {
+			let item = pricer.pet(pet);
+			networth.add_item(NetworthCategory::Pets, item);
+		}
+
+		for (id, count) in self.inventory.sacks_counts.iter().filter(|(_, count)| **count > 0) {
+			let item = pricer.item(id.clone(), id.clone(), *count, vec![]);
+			networth.add_item(NetworthCategory::Sacks, item);
+		}
+
+		networth.unpriced = pricer.unpriced;
+		networth.sort();
+
+		Ok(networth)
+	}
+}
+
+impl Profile {
+	/// Works out what a member is worth, including the profile's bank.
+	/// The bank is shared, so it's counted in full for every member of a co-op.
+	/// Returns `None` if the player isn't a member of the profile.
+	pub fn networth<P: PriceSource + ?Sized>(&self, uuid: &str, prices: &P, valuation: &Valuation) -> Option<Result<Networth>> {
+		let member = self.member(uuid)?;
+
+		Some(member.networth(prices, valuation).map(|mut networth| {
+			if let Some(banking) = &self.banking {
+				networth.add_coins(NetworthCategory::Bank, banking.balance);
+			}
+
+			networth
+		}))
+	}
+}
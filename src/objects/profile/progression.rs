@@ -0,0 +1,245 @@
+use crate::objects::profile::ProfileMember;
+use crate::objects::resources::Skill;
+
+/// The XP needed for each level of a regular skill.
+const SKILL_XP: [f64; 60] = [
+	50.0, 125.0, 200.0, 300.0, 500.0, 750.0, 1000.0, 1500.0, 2000.0, 3500.0,
+	5000.0, 7500.0, 10000.0, 15000.0, 20000.0, 30000.0, 50000.0, 75000.0, 100000.0, 200000.0,
+	300000.0, 400000.0, 500000.0, 600000.0, 700000.0, 800000.0, 900000.0, 1000000.0, 1100000.0, 1200000.0,
+	1300000.0, 1400000.0, 1500000.0, 1600000.0, 1700000.0, 1800000.0, 1900000.0, 2000000.0, 2100000.0, 2200000.0,
+	2300000.0, 2400000.0, 2500000.0, 2600000.0, 2750000.0, 2900000.0, 3100000.0, 3400000.0, 3700000.0, 4000000.0,
+	4300000.0, 4600000.0, 4900000.0, 5200000.0, 5500000.0, 5800000.0, 6100000.0, 6400000.0, 6700000.0, 7000000.0,
+];
+
+/// The XP needed for each level of runecrafting.
+const RUNECRAFTING_XP: [f64; 25] = [
+	50.0, 100.0, 125.0, 160.0, 200.0, 250.0, 315.0, 400.0, 500.0, 625.0,
+	785.0, 1000.0, 1250.0, 1600.0, 2000.0, 2465.0, 3125.0, 4000.0, 5000.0, 6200.0,
+	7800.0, 9800.0, 12200.0, 15300.0, 19050.0,
+];
+
+/// The XP needed for each level of social.
+const SOCIAL_XP: [f64; 25] = [
+	50.0, 100.0, 150.0, 250.0, 500.0, 750.0, 1000.0, 1250.0, 1500.0, 2000.0,
+	2500.0, 3000.0, 3750.0, 4500.0, 6000.0, 8000.0, 10000.0, 12500.0, 15000.0, 20000.0,
+	25000.0, 30000.0, 35000.0, 40000.0, 50000.0,
+];
+
+/// The XP needed for each level of catacombs, and of each dungeon class.
+const CATACOMBS_XP: [f64; 50] = [
+	50.0, 75.0, 110.0, 160.0, 230.0, 330.0, 470.0, 670.0, 950.0, 1340.0,
+	1890.0, 2665.0, 3760.0, 5260.0, 7380.0, 10300.0, 14400.0, 20000.0, 27600.0, 38000.0,
+	52500.0, 71500.0, 97000.0, 132000.0, 180000.0, 243000.0, 328000.0, 445000.0, 600000.0, 800000.0,
+	1065000.0, 1410000.0, 1900000.0, 2500000.0, 3300000.0, 4300000.0, 5600000.0, 7200000.0, 9200000.0, 12000000.0,
+	15000000.0, 19000000.0, 24000000.0, 30000000.0, 38000000.0, 48000000.0, 60000000.0, 75000000.0, 93000000.0, 116250000.0,
+];
+
+/// The total XP needed for each level of the zombie slayer.
+const ZOMBIE_SLAYER_XP: [f64; 9] = [5.0, 15.0, 200.0, 1000.0, 5000.0, 20000.0, 100000.0, 400000.0, 1000000.0];
+
+/// The total XP needed for each level of the spider slayer.
+const SPIDER_SLAYER_XP: [f64; 9] = [5.0, 25.0, 200.0, 1000.0, 5000.0, 20000.0, 100000.0, 400000.0, 1000000.0];
+
+/// The total XP needed for each level of the wolf, enderman and blaze slayers.
+const LATER_SLAYER_XP: [f64; 9] = [10.0, 30.0, 250.0, 1500.0, 5000.0, 20000.0, 100000.0, 400000.0, 1000000.0];
+
+/// The total XP needed for each level of the vampire slayer.
+const VAMPIRE_SLAYER_XP: [f64; 5] = [20.0, 75.0, 240.0, 840.0, 2400.0];
+
+/// The skills counted towards the skill average; runecrafting and social are cosmetic.
+pub const AVERAGED_SKILLS: [&str; 9] = [
+	"farming", "mining", "combat", "foraging", "fishing", "enchanting", "alchemy", "taming", "carpentry",
+];
+
+/// A level reached with some amount of XP.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct Level {
+	pub level: i32,
+	pub max_level: i32,
+	/// The XP the level was worked out from
+	pub xp: f64,
+	/// The fraction of the way to the next level, zero at the max level
+	pub progress: f64,
+	/// The XP left to reach the next level, `None` at the max level
+	pub xp_to_next: Option<f64>,
+	/// The XP earned beyond the max level
+	pub overflow: f64,
+}
+
+impl Level {
+	/// The level with the progress to the next one as a fraction, e.g. `24.5`.
+	pub fn fractional(&self) -> f64 {
+		self.level as f64 + self.progress
+	}
+
+	pub fn is_maxed(&self) -> bool {
+		self.level >= self.max_level
+	}
+}
+
+/// The total XP required to reach each level, starting from level one.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct XpTable {
+	totals: Vec<f64>,
+}
+
+impl XpTable {
+	/// Builds a table from the XP required to reach each level from the previous one.
+	pub fn from_increments(increments: &[f64]) -> XpTable {
+		let totals = increments.iter()
+			.scan(0.0, |total, xp| {
+				*total += xp;
+				Some(*total)
+			})
+			.collect();
+
+		XpTable { totals }
+	}
+
+	/// Builds a table from the total XP required to reach each level.
+	pub fn from_totals(totals: &[f64]) -> XpTable {
+		XpTable { totals: totals.to_vec() }
+	}
+
+	/// The bundled table for a skill, e.g. `farming`, capped at the skill's max level.
+	pub fn skill(skill: &str) -> XpTable {
+		match skill {
+			"runecrafting" => Self::from_increments(&RUNECRAFTING_XP),
+			"social" => Self::from_increments(&SOCIAL_XP),
+			"foraging" | "fishing" | "alchemy" | "carpentry" => Self::from_increments(&SKILL_XP[..50]),
+			_ => Self::from_increments(&SKILL_XP),
+		}
+	}
+
+	/// The bundled table for a slayer boss, e.g. `zombie`.
+	pub fn slayer(boss: &str) -> Option<XpTable> {
+		match boss {
+			"zombie" => Some(Self::from_totals(&ZOMBIE_SLAYER_XP)),
+			"spider" => Some(Self::from_totals(&SPIDER_SLAYER_XP)),
+			"wolf" | "enderman" | "blaze" => Some(Self::from_totals(&LATER_SLAYER_XP)),
+			"vampire" => Some(Self::from_totals(&VAMPIRE_SLAYER_XP)),
+			_ => None
+		}
+	}
+
+	/// The bundled table for catacombs, which the dungeon classes share.
+	pub fn catacombs() -> XpTable {
+		Self::from_increments(&CATACOMBS_XP)
+	}
+
+	pub fn max_level(&self) -> i32 {
+		self.totals.len() as i32
+	}
+
+	/// The total XP required to reach `level`, `None` if it's out of range.
+	pub fn xp_for_level(&self, level: i32) -> Option<f64> {
+		match level {
+			0 => Some(0.0),
+			_ if level < 0 => None,
+			_ => self.totals.get(level as usize - 1).copied()
+		}
+	}
+
+	pub fn level(&self, xp: f64) -> Level {
+		let level = self.totals.iter().take_while(|total| xp >= **total).count();
+		let floor = self.xp_for_level(level as i32).unwrap_or(0.0);
+
+		match self.totals.get(level) {
+			Some(next) => Level {
+				level: level as i32,
+				max_level: self.max_level(),
+				xp,
+				progress: (xp - floor) / (next - floor),
+				xp_to_next: Some(next - xp),
+				overflow: 0.0,
+			},
+			None => Level {
+				level: level as i32,
+				max_level: self.max_level(),
+				xp,
+				progress: 0.0,
+				xp_to_next: None,
+				overflow: xp - floor,
+			}
+		}
+	}
+}
+
+impl From<&Skill> for XpTable {
+	/// The table for a skill from the skills resource, which stays current with game updates.
+	fn from(skill: &Skill) -> Self {
+		let mut levels: Vec<_> = skill.levels.iter()
+			.filter(|level| level.level <= skill.max_level)
+			.collect();
+		levels.sort_by_key(|level| level.level);
+
+		XpTable {
+			totals: levels.into_iter().map(|level| level.total_exp_required).collect(),
+		}
+	}
+}
+
+impl ProfileMember {
+	/// The XP of a skill, e.g. `farming`, `None` if the skills API setting is disabled.
+	pub fn skill_xp(&self, skill: &str) -> Option<f64> {
+		self.player_data.experience
+			.get(&format!("SKILL_{}", skill.to_uppercase()))
+			.copied()
+	}
+
+	/// The level of a skill, using the bundled XP tables.
+	pub fn skill_level(&self, skill: &str) -> Option<Level> {
+		self.skill_level_with(skill, &XpTable::skill(skill))
+	}
+
+	/// The level of a skill, using the given XP table, such as one from the skills resource.
+	pub fn skill_level_with(&self, skill: &str, table: &XpTable) -> Option<Level> {
+		self.skill_xp(skill).map(|xp| table.level(xp))
+	}
+
+	/// The mean fractional level of the non-cosmetic skills, `None` if the skills API setting is disabled.
+	/// Skills the member has never trained are missing from the API, and count as level zero.
+	pub fn skill_average(&self) -> Option<f64> {
+		if self.player_data.experience.is_empty() {
+			return None;
+		}
+
+		let total: f64 = AVERAGED_SKILLS.iter()
+			.map(|skill| XpTable::skill(skill).level(self.skill_xp(skill).unwrap_or(0.0)).fractional())
+			.sum();
+
+		Some(total / AVERAGED_SKILLS.len() as f64)
+	}
+
+	/// The slayer XP earned against a boss, e.g. `zombie`.
+	pub fn slayer_xp(&self, boss: &str) -> f64 {
+		self.slayer.slayer_bosses.get(boss).map_or(0.0, |boss| boss.xp)
+	}
+
+	/// The slayer level against a boss, `None` for bosses without a known XP table.
+	pub fn slayer_level(&self, boss: &str) -> Option<Level> {
+		XpTable::slayer(boss).map(|table| table.level(self.slayer_xp(boss)))
+	}
+
+	/// The total slayer XP across every boss.
+	pub fn total_slayer_xp(&self) -> f64 {
+		self.slayer.slayer_bosses.values().map(|boss| boss.xp).sum()
+	}
+
+	pub fn catacombs_xp(&self) -> f64 {
+		self.dungeons.dungeon_types.get("catacombs").map_or(0.0, |stats| stats.experience)
+	}
+
+	pub fn catacombs_level(&self) -> Level {
+		XpTable::catacombs().level(self.catacombs_xp())
+	}
+
+	/// The XP of a dungeon class, e.g. `healer`.
+	pub fn class_xp(&self, class: &str) -> f64 {
+		self.dungeons.player_classes.get(class).map_or(0.0, |stats| stats.experience)
+	}
+
+	pub fn class_level(&self, class: &str) -> Level {
+		XpTable::catacombs().level(self.class_xp(class))
+	}
+}
@@ -0,0 +1,220 @@
+use crate::objects::profile::{Container, Profile, ProfileMember};
+use crate::Result;
+use std::collections::{BTreeMap, HashMap};
+
+/// An item that can be told apart from every other, by the UUID in its NBT.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TrackedItem {
+	pub uuid: String,
+	pub id: String,
+	/// The item's display name when the snapshot was taken, which renaming can change
+	pub name: String,
+	pub count: i64,
+	pub container: Container,
+}
+
+/// The state of a member at a point in time, to be compared with a later one.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct MemberSnapshot {
+	/// When the snapshot was taken, in milliseconds since the epoch
+	pub timestamp: i64,
+	pub purse: f64,
+	/// The balance of the profile's bank, `None` if the banking API setting is disabled
+	pub bank: Option<f64>,
+	/// XP per skill, keyed by lowercase name, e.g. `mining`
+	pub skills: HashMap<String, f64>,
+	/// XP per slayer boss, e.g. `zombie`
+	pub slayers: HashMap<String, f64>,
+	pub catacombs_xp: f64,
+	/// Items collected, keyed by item ID
+	pub collections: HashMap<String, i64>,
+	/// Items with a UUID, keyed by it, so they can be followed between containers;
+	/// always empty without the `nbt` feature
+	pub items: HashMap<String, TrackedItem>,
+}
+
+/// An item which is in a different container than before.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MovedItem {
+	/// The item, where it is now
+	pub item: TrackedItem,
+	/// Where the item was before
+	pub from: Container,
+}
+
+/// What changed between two snapshots of a member. Values are the later minus the earlier.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct MemberDiff {
+	pub from: i64,
+	pub to: i64,
+	pub purse: f64,
+	/// `None` unless both snapshots have a bank balance
+	pub bank: Option<f64>,
+	/// Changes in XP per skill, leaving out skills which didn't change
+	pub skill_xp: BTreeMap<String, f64>,
+	pub slayer_xp: BTreeMap<String, f64>,
+	pub catacombs_xp: f64,
+	/// Changes in items collected, leaving out items which didn't change
+	pub collections: BTreeMap<String, i64>,
+	pub items_added: Vec<TrackedItem>,
+	pub items_removed: Vec<TrackedItem>,
+	pub items_moved: Vec<MovedItem>,
+}
+
+impl MemberDiff {
+	/// Whether nothing changed between the snapshots.
+	pub fn is_empty(&self) -> bool {
+		self.purse == 0.0
+			&& self.bank.unwrap_or(0.0) == 0.0
+			&& self.skill_xp.is_empty()
+			&& self.slayer_xp.is_empty()
+			&& self.catacombs_xp == 0.0
+			&& self.collections.is_empty()
+			&& self.items_added.is_empty()
+			&& self.items_removed.is_empty()
+			&& self.items_moved.is_empty()
+	}
+
+	/// The XP gained across every skill.
+	pub fn total_skill_xp(&self) -> f64 {
+		self.skill_xp.values().sum()
+	}
+
+	/// The time between the snapshots, in milliseconds.
+	pub fn duration(&self) -> i64 {
+		self.to - self.from
+	}
+}
+
+/// The changes in a map of values, leaving out those that didn't change.
+fn changes<T>(before: &HashMap<String, T>, after: &HashMap<String, T>) -> BTreeMap<String, T> where
+	T: Copy + Default + PartialEq + std::ops::Sub<Output = T> {
+	before.keys()
+		.chain(after.keys())
+		.filter_map(|key| {
+			let before = before.get(key).copied().unwrap_or_default();
+			let after = after.get(key).copied().unwrap_or_default();
+
+			Some((key.clone(), after - before)).filter(|(_, change)| *change != T::default())
+		})
+		.collect()
+}
+
+fn sort_items(items: &mut [TrackedItem]) {
+	items.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+}
+
+/// Every item with a UUID across the member's containers, keyed by it.
+#[cfg(feature = "nbt")]
+fn tracked_items(member: &ProfileMember) -> Result<HashMap<String, TrackedItem>> {
+	let mut items = HashMap::new();
+
+	for (container, bytes) in member.inventory.containers() {
+		for slot in bytes.to_inventory()?.i {
+			let tag = match slot.tag {
+				Some(tag) => tag,
+				None => continue,
+			};
+			let uuid = match tag.extra_attributes.uuid {
+				Some(uuid) => uuid,
+				None => continue,
+			};
+
+			items.insert(uuid.clone(), TrackedItem {
+				uuid,
+				id: tag.extra_attributes.id,
+				name: tag.display.name,
+				count: slot.count.unwrap_or(1) as i64,
+				container: container.clone(),
+			});
+		}
+	}
+
+	Ok(items)
+}
+
+/// Containers can't be decoded without the `nbt` feature, so no items are tracked.
+#[cfg(not(feature = "nbt"))]
+fn tracked_items(_member: &ProfileMember) -> Result<HashMap<String, TrackedItem>> {
+	Ok(HashMap::new())
+}
+
+impl MemberSnapshot {
+	/// Works out what changed between this snapshot and a later one.
+	pub fn diff(&self, later: &MemberSnapshot) -> MemberDiff {
+		let mut items_added: Vec<TrackedItem> = later.items.values()
+			.filter(|item| !self.items.contains_key(&item.uuid))
+			.cloned()
+			.collect();
+		let mut items_removed: Vec<TrackedItem> = self.items.values()
+			.filter(|item| !later.items.contains_key(&item.uuid))
+			.cloned()
+			.collect();
+		let mut items_moved: Vec<MovedItem> = later.items.values()
+			.filter_map(|item| {
+				let before = self.items.get(&item.uuid)?;
+
+				Some(MovedItem { item: item.clone(), from: before.container.clone() })
+					.filter(|moved| moved.item.container != moved.from)
+			})
+			.collect();
+
+		sort_items(&mut items_added);
+		sort_items(&mut items_removed);
+		items_moved.sort_by(|a, b| a.item.uuid.cmp(&b.item.uuid));
+
+		MemberDiff {
+			from: self.timestamp,
+			to: later.timestamp,
+			purse: later.purse - self.purse,
+			bank: self.bank.and_then(|before| later.bank.map(|after| after - before)),
+			skill_xp: changes(&self.skills, &later.skills),
+			slayer_xp: changes(&self.slayers, &later.slayers),
+			catacombs_xp: later.catacombs_xp - self.catacombs_xp,
+			collections: changes(&self.collections, &later.collections),
+			items_added,
+			items_removed,
+			items_moved,
+		}
+	}
+}
+
+impl ProfileMember {
+	/// Records the member's coins, XP, collections and items as of `timestamp`, to diff against later.
+	/// The bank belongs to the profile, so it's left as `None`; `Profile::snapshot` fills it in.
+	/// Stackable items such as materials have no UUID, so they're followed through the collections.
+	pub fn snapshot(&self, timestamp: i64) -> Result<MemberSnapshot> {
+		let skills = self.player_data.experience.iter()
+			.filter_map(|(key, xp)| Some((key.strip_prefix("SKILL_")?.to_lowercase(), *xp)))
+			.collect();
+		let slayers = self.slayer.slayer_bosses.iter()
+			.map(|(boss, data)| (boss.clone(), data.xp))
+			.collect();
+
+		let items = tracked_items(self)?;
+
+		Ok(MemberSnapshot {
+			timestamp,
+			purse: self.currencies.coin_purse,
+			bank: None,
+			skills,
+			slayers,
+			catacombs_xp: self.catacombs_xp(),
+			collections: self.collection.clone(),
+			items,
+		})
+	}
+}
+
+impl Profile {
+	/// Snapshots the member with the UUID `uuid`, along with the bank balance at the time.
+	/// `None` if nobody on the profile has that UUID.
+	pub fn snapshot(&self, uuid: &str, timestamp: i64) -> Option<Result<MemberSnapshot>> {
+		let member = self.member(uuid)?;
+
+		Some(member.snapshot(timestamp).map(|mut snapshot| {
+			snapshot.bank = self.banking.as_ref().map(|banking| banking.balance);
+			snapshot
+		}))
+	}
+}
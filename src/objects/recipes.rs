@@ -0,0 +1,151 @@
+pub mod craft;
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// The compactions of plain items into enchanted ones, as (output, input, inputs per output).
+const COMPACTIONS: [(&str, &str, i64); 40] = [
+	("ENCHANTED_COBBLESTONE", "COBBLESTONE", 160),
+	("ENCHANTED_COAL", "COAL", 160),
+	("ENCHANTED_COAL_BLOCK", "ENCHANTED_COAL", 160),
+	("ENCHANTED_IRON", "IRON_INGOT", 160),
+	("ENCHANTED_IRON_BLOCK", "ENCHANTED_IRON", 160),
+	("ENCHANTED_GOLD", "GOLD_INGOT", 160),
+	("ENCHANTED_GOLD_BLOCK", "ENCHANTED_GOLD", 160),
+	("ENCHANTED_DIAMOND", "DIAMOND", 160),
+	("ENCHANTED_DIAMOND_BLOCK", "ENCHANTED_DIAMOND", 160),
+	("ENCHANTED_EMERALD", "EMERALD", 160),
+	("ENCHANTED_EMERALD_BLOCK", "ENCHANTED_EMERALD", 160),
+	("ENCHANTED_REDSTONE", "REDSTONE", 160),
+	("ENCHANTED_REDSTONE_BLOCK", "ENCHANTED_REDSTONE", 160),
+	("ENCHANTED_LAPIS_LAZULI", "INK_SACK:4", 160),
+	("ENCHANTED_LAPIS_LAZULI_BLOCK", "ENCHANTED_LAPIS_LAZULI", 160),
+	("ENCHANTED_QUARTZ", "QUARTZ", 160),
+	("ENCHANTED_QUARTZ_BLOCK", "ENCHANTED_QUARTZ", 4),
+	("ENCHANTED_OBSIDIAN", "OBSIDIAN", 160),
+	("ENCHANTED_GLOWSTONE_DUST", "GLOWSTONE_DUST", 192),
+	("ENCHANTED_ICE", "ICE", 160),
+	("ENCHANTED_PACKED_ICE", "ENCHANTED_ICE", 160),
+	("ENCHANTED_SAND", "SAND", 160),
+	("ENCHANTED_FLINT", "FLINT", 160),
+	("ENCHANTED_SUGAR", "SUGAR_CANE", 160),
+	("ENCHANTED_SUGAR_CANE", "ENCHANTED_SUGAR", 160),
+	("ENCHANTED_CARROT", "CARROT_ITEM", 160),
+	("ENCHANTED_POTATO", "POTATO_ITEM", 160),
+	("ENCHANTED_BAKED_POTATO", "ENCHANTED_POTATO", 160),
+	("ENCHANTED_PUMPKIN", "PUMPKIN", 160),
+	("ENCHANTED_MELON", "MELON", 160),
+	("ENCHANTED_MELON_BLOCK", "ENCHANTED_MELON", 160),
+	("ENCHANTED_BREAD", "WHEAT", 60),
+	("ENCHANTED_BONE", "BONE", 160),
+	("ENCHANTED_ROTTEN_FLESH", "ROTTEN_FLESH", 160),
+	("ENCHANTED_STRING", "STRING", 192),
+	("ENCHANTED_GUNPOWDER", "SULPHUR", 160),
+	("ENCHANTED_ENDER_PEARL", "ENDER_PEARL", 20),
+	("ENCHANTED_SLIME_BALL", "SLIME_BALL", 160),
+	("ENCHANTED_SLIME_BLOCK", "ENCHANTED_SLIME_BALL", 160),
+	("ENCHANTED_FEATHER", "FEATHER", 160),
+];
+
+/// A way of crafting an item.
+/// Recipes making fewer than one item are rejected when deserialized or inserted into `Recipes`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(try_from = "RecipeData")]
+pub struct Recipe {
+	/// The number of the item one craft makes
+	pub count: i64,
+	/// The items used up by one craft, keyed by item ID
+	pub ingredients: BTreeMap<String, i64>,
+}
+
+/// A recipe as written, before its count is checked.
+#[derive(Deserialize)]
+struct RecipeData {
+	#[serde(default = "one")]
+	count: i64,
+	ingredients: BTreeMap<String, i64>,
+}
+
+fn one() -> i64 { 1 }
+
+impl TryFrom<RecipeData> for Recipe {
+	type Error = String;
+
+	fn try_from(data: RecipeData) -> Result<Self, Self::Error> {
+		let recipe = Recipe { count: data.count, ingredients: data.ingredients };
+
+		recipe.check().map(|_| recipe)
+	}
+}
+
+impl Recipe {
+	/// A recipe compacting `count` of a single item into one.
+	pub fn compaction(input: &str, count: i64) -> Recipe {
+		let mut ingredients = BTreeMap::new();
+		ingredients.insert(input.to_owned(), count);
+
+		Recipe { count: 1, ingredients }
+	}
+
+	fn check(&self) -> Result<(), String> {
+		if self.count > 0 {
+			Ok(())
+		} else {
+			Err(format!("recipe must make at least one item, got {}", self.count))
+		}
+	}
+}
+
+/// Recipes keyed by the ID of the item they make.
+/// Deserializes from a map of item IDs to recipes, so a full set can be loaded from a file.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[serde(transparent)]
+pub struct Recipes {
+	recipes: BTreeMap<String, Recipe>,
+}
+
+impl Recipes {
+	pub fn new() -> Recipes {
+		Self::default()
+	}
+
+	/// The bundled recipes, covering the compaction of common items into enchanted ones.
+	pub fn bundled() -> Recipes {
+		let mut recipes = Self::new();
+
+		for (output, input, count) in COMPACTIONS.iter() {
+			recipes.recipes.insert(output.to_string(), Recipe::compaction(input, *count));
+		}
+
+		recipes
+	}
+
+	/// Adds a recipe, replacing and returning any other recipe for the item.
+	/// Fails if the recipe makes fewer than one item.
+	pub fn insert(&mut self, id: &str, recipe: Recipe) -> Result<Option<Recipe>, String> {
+		recipe.check()?;
+
+		Ok(self.recipes.insert(id.to_owned(), recipe))
+	}
+
+	/// Adds every recipe from another set, replacing any recipes for the same items.
+	pub fn extend(&mut self, other: Recipes) {
+		self.recipes.extend(other.recipes);
+	}
+
+	pub fn get(&self, id: &str) -> Option<&Recipe> {
+		self.recipes.get(id)
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&String, &Recipe)> {
+		self.recipes.iter()
+	}
+
+	pub fn len(&self) -> usize {
+		self.recipes.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.recipes.is_empty()
+	}
+}
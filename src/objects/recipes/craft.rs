@@ -0,0 +1,155 @@
+use crate::objects::pricing::PriceSource;
+use crate::objects::recipes::Recipes;
+
+/// How an item is best obtained.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum Method {
+	Buy,
+	/// Crafted from its ingredients, each craft making `makes` of the item
+	Craft {
+		makes: i64,
+		inputs: Vec<Input>,
+	},
+}
+
+/// An ingredient of a craft, and how it's best obtained.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Input {
+	/// The number of the ingredient one craft uses up
+	pub count: i64,
+	pub plan: CraftPlan,
+}
+
+/// The cheapest way found of obtaining an item.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct CraftPlan {
+	pub id: String,
+	/// The coins it takes to obtain one of the item
+	pub unit_cost: f64,
+	pub method: Method,
+}
+
+impl CraftPlan {
+	/// The number of the item the plan has to buy to obtain one of `id`, summed over every step.
+	pub fn bought(&self, id: &str) -> f64 {
+		match &self.method {
+			Method::Buy if self.id == id => 1.0,
+			Method::Buy => 0.0,
+			Method::Craft { makes, inputs } => inputs.iter()
+				.map(|input| input.count as f64 * input.plan.bought(id))
+				.sum::<f64>() / *makes as f64
+		}
+	}
+}
+
+/// The estimated profitability of crafting an item and selling it.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct CraftFlip {
+	pub id: String,
+	/// The coins it takes to craft one of the item
+	pub craft_cost: f64,
+	/// The coins one of the item sells for
+	pub sell_price: f64,
+	pub profit: f64,
+	/// The profit relative to the craft cost
+	pub margin_ratio: f64,
+	pub plan: CraftPlan,
+}
+
+/// Works out the cheapest way to obtain items, choosing between buying each ingredient
+/// and crafting it from its own recipe, all the way down.
+pub struct CraftCalculator<'a, P: ?Sized> {
+	recipes: &'a Recipes,
+	prices: &'a P,
+}
+
+impl<'a, P: PriceSource + ?Sized> CraftCalculator<'a, P> {
+	pub fn new(recipes: &'a Recipes, prices: &'a P) -> CraftCalculator<'a, P> {
+		CraftCalculator { recipes, prices }
+	}
+
+	/// The cheapest way to obtain one of the item, `None` if it can neither be bought nor crafted.
+	pub fn plan(&self, id: &str) -> Option<CraftPlan> {
+		self.cheapest(id, &mut vec![])
+	}
+
+	/// The cheapest way to craft one of the item, buying or crafting its ingredients.
+	/// `None` if there's no recipe, or an ingredient can't be obtained.
+	pub fn craft(&self, id: &str) -> Option<CraftPlan> {
+		self.craft_with(id, &mut vec![id.to_owned()])
+	}
+
+	/// The profitability of crafting the item rather than buying it.
+	/// `None` if the craft costs nothing, as there's no margin to speak of.
+	pub fn flip(&self, id: &str) -> Option<CraftFlip> {
+		let plan = self.craft(id).filter(|plan| plan.unit_cost > 0.0)?;
+		let sell_price = self.prices.sell_price(id)?;
+		let profit = sell_price - plan.unit_cost;
+
+		Some(CraftFlip {
+			id: id.to_owned(),
+			craft_cost: plan.unit_cost,
+			sell_price,
+			profit,
+			margin_ratio: profit / plan.unit_cost,
+			plan,
+		})
+	}
+
+	/// Every craft that sells for more than it costs, most profitable first.
+	pub fn flips(&self) -> Vec<CraftFlip> {
+		let mut flips: Vec<CraftFlip> = self.recipes.iter()
+			.filter_map(|(id, _)| self.flip(id))
+			.filter(|flip| flip.profit > 0.0)
+			.collect();
+		flips.sort_by(|a, b| b.profit.partial_cmp(&a.profit).unwrap_or(std::cmp::Ordering::Equal));
+
+		flips
+	}
+
+	/// `visiting` holds the items being crafted further up, so cyclic recipes can't recurse forever.
+	fn cheapest(&self, id: &str, visiting: &mut Vec<String>) -> Option<CraftPlan> {
+		let bought = self.prices.buy_price(id).map(|unit_cost| CraftPlan {
+			id: id.to_owned(),
+			unit_cost,
+			method: Method::Buy,
+		});
+
+		if visiting.iter().any(|item| item == id) {
+			return bought;
+		}
+
+		visiting.push(id.to_owned());
+		let crafted = self.craft_with(id, visiting);
+		visiting.pop();
+
+		match (bought, crafted) {
+			(Some(bought), Some(crafted)) if crafted.unit_cost < bought.unit_cost => Some(crafted),
+			(Some(bought), _) => Some(bought),
+			(None, crafted) => crafted,
+		}
+	}
+
+	fn craft_with(&self, id: &str, visiting: &mut Vec<String>) -> Option<CraftPlan> {
+		let recipe = self.recipes.get(id)?;
+		let mut inputs = Vec::with_capacity(recipe.ingredients.len());
+
+		for (ingredient, count) in &recipe.ingredients {
+			inputs.push(Input {
+				count: *count,
+				plan: self.cheapest(ingredient, visiting)?,
+			});
+		}
+
+		let cost: f64 = inputs.iter().map(|input| input.count as f64 * input.plan.unit_cost).sum();
+
+		Some(CraftPlan {
+			id: id.to_owned(),
+			unit_cost: cost / recipe.count as f64,
+			method: Method::Craft {
+				makes: recipe.count,
+				inputs,
+			},
+		})
+	}
+}
@@ -0,0 +1,156 @@
+use crate::objects::items::Rarity;
+use serde::{Deserialize, Deserializer, Serializer};
+use std::collections::HashMap;
+
+/// Every item in the game, from `resources/skyblock/items`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ItemsResource {
+	/// Unix time (in milliseconds) of when the resource was last changed
+	#[serde(rename = "lastUpdated")]
+	pub last_updated: i64,
+	/// The items, keyed by their ID
+	#[serde(with = "items_by_id")]
+	pub items: HashMap<String, SkyblockItem>,
+}
+
+impl ItemsResource {
+	pub fn get(&self, id: &str) -> Option<&SkyblockItem> {
+		self.items.get(id)
+	}
+
+	/// The display name of an item ID.
+	pub fn name(&self, id: &str) -> Option<&str> {
+		self.get(id).map(|item| item.name.as_str())
+	}
+
+	/// The coins an NPC pays for one of the item, if it can be sold to one.
+	pub fn npc_sell_price(&self, id: &str) -> Option<f64> {
+		self.get(id)?.npc_sell_price
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SkyblockItem {
+	pub id: String,
+	/// The Minecraft material the item is based on
+	pub material: String,
+	pub name: String,
+	/// The base rarity of the item, absent for items without one
+	pub tier: Option<Rarity>,
+	/// The kind of item, e.g. `SWORD` or `ACCESSORY`
+	pub category: Option<String>,
+	pub npc_sell_price: Option<f64>,
+	/// The base stats of the item, keyed by stat, e.g. `DAMAGE`
+	#[serde(default)]
+	pub stats: HashMap<String, f64>,
+}
+
+/// The collections and their tiers, from `resources/skyblock/collections`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CollectionsResource {
+	#[serde(rename = "lastUpdated")]
+	pub last_updated: i64,
+	/// The game version the resource describes
+	pub version: String,
+	/// The categories of collections, keyed by ID, e.g. `FARMING`
+	pub collections: HashMap<String, CollectionCategory>,
+}
+
+impl CollectionsResource {
+	/// Looks up a collection by its item ID, across all categories.
+	pub fn get(&self, id: &str) -> Option<&Collection> {
+		self.collections.values().find_map(|category| category.items.get(id))
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CollectionCategory {
+	pub name: String,
+	/// The collections in the category, keyed by item ID
+	pub items: HashMap<String, Collection>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Collection {
+	pub name: String,
+	#[serde(rename = "maxTiers")]
+	pub max_tiers: i32,
+	pub tiers: Vec<CollectionTier>,
+}
+
+impl Collection {
+	/// The highest tier reached having collected `amount` of the item.
+	pub fn tier_for(&self, amount: i64) -> i32 {
+		self.tiers.iter()
+			.filter(|tier| tier.amount_required <= amount)
+			.map(|tier| tier.tier)
+			.max()
+			.unwrap_or(0)
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CollectionTier {
+	pub tier: i32,
+	#[serde(rename = "amountRequired")]
+	pub amount_required: i64,
+	/// Descriptions of the rewards for reaching the tier
+	pub unlocks: Vec<String>,
+}
+
+/// The skills and their XP tables, from `resources/skyblock/skills`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SkillsResource {
+	#[serde(rename = "lastUpdated")]
+	pub last_updated: i64,
+	pub version: String,
+	/// The skills, keyed by ID, e.g. `FARMING`
+	pub skills: HashMap<String, Skill>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Skill {
+	pub name: String,
+	pub description: String,
+	#[serde(rename = "maxLevel")]
+	pub max_level: i32,
+	pub levels: Vec<SkillLevel>,
+}
+
+impl Skill {
+	/// The total XP required to reach `level`, `None` if it's out of range.
+	pub fn xp_for_level(&self, level: i32) -> Option<f64> {
+		match level {
+			0 => Some(0.0),
+			_ => self.levels.iter()
+				.find(|l| l.level == level)
+				.map(|l| l.total_exp_required)
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SkillLevel {
+	pub level: i32,
+	/// The total XP required to reach the level from zero
+	#[serde(rename = "totalExpRequired")]
+	pub total_exp_required: f64,
+	pub unlocks: Vec<String>,
+}
+
+/// The API lists items as an array; they're keyed by ID here for lookups.
+mod items_by_id {
+	use super::*;
+
+	pub fn serialize<S>(items: &HashMap<String, SkyblockItem>, serializer: S) -> Result<S::Ok, S::Error> where
+		S: Serializer {
+		serializer.collect_seq(items.values())
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<String, SkyblockItem>, D::Error> where
+		D: Deserializer<'de> {
+		let items: Vec<SkyblockItem> = Vec::deserialize(deserializer)?;
+
+		Ok(items.into_iter().map(|item| (item.id.clone(), item)).collect())
+	}
+}
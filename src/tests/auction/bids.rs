@@ -0,0 +1,66 @@
+use crate::objects::*;
+use std::time::{Duration, UNIX_EPOCH};
+
+fn auction() -> Auction {
+	serde_json::from_str(include_str!("../deserialize/auction/auction.json")).unwrap()
+}
+
+fn bid(auction: &Auction, bidder: &str, amount: i64, timestamp: i64) -> Bid {
+	Bid {
+		auction_id: auction.uuid.clone(),
+		bidder: PartialProfile(bidder.to_string()),
+		amount,
+		timestamp,
+	}
+}
+
+#[test]
+fn bidders() {
+	let mut auction = auction();
+	let repeat = bid(&auction, "be366506d9cd415687a9a1c90a888550", 600, 1579070000000);
+	auction.bids.bids.push(repeat);
+
+	assert_eq!(auction.bids.count(), 3);
+	assert_eq!(auction.bids.unique_bidders(), 2);
+	assert_eq!(auction.bids.last_bid().unwrap().timestamp, 1579070000000);
+	assert!(auction.bids.is_winning(&PartialProfile("be366506d9cd415687a9a1c90a888550".to_string())));
+	assert!(!auction.bids.is_winning(&PartialProfile("f51cde9dc90946b1b1c3da9737817fdd".to_string())));
+}
+
+#[test]
+fn velocity() {
+	let auction = auction();
+	let now = auction.bids.last_bid_time().unwrap();
+
+	assert_eq!(auction.bids.velocity(Duration::from_secs(60 * 60), now), 1.0);
+	assert_eq!(auction.bids.velocity(Duration::from_secs(3 * 60 * 60), now), 2.0 / 3.0);
+	assert_eq!(auction.bids.velocity(Duration::from_secs(0), now), 0.0);
+}
+
+#[test]
+fn velocity_before_epoch() {
+	let auction = auction();
+	let now = auction.bids.last_bid_time().unwrap();
+	let hours = now.duration_since(UNIX_EPOCH).unwrap().as_secs_f64() / 3600.0;
+
+	// The window is cut short at the epoch rather than panicking.
+	assert_eq!(auction.bids.velocity(Duration::from_secs(u64::MAX), now), auction.bids.count() as f64 / hours);
+	assert_eq!(auction.bids.velocity(Duration::from_secs(60), UNIX_EPOCH), 0.0);
+}
+
+#[test]
+fn extensions() {
+	let mut auction = auction();
+
+	assert!(auction.extensions().is_empty());
+	assert_eq!(auction.projected_end(), auction.end);
+
+	let end = auction.end;
+	let first = bid(&auction, "a", 600, end - 30_000);
+	let second = bid(&auction, "b", 700, end + 60_000);
+	auction.bids.bids.push(second.clone());
+	auction.bids.bids.push(first.clone());
+
+	assert_eq!(auction.extensions(), vec![&first, &second]);
+	assert_eq!(auction.projected_end(), end + 180_000);
+}
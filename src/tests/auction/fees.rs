@@ -0,0 +1,65 @@
+use crate::objects::*;
+use crate::objects::auction::fees::*;
+use std::time::Duration;
+
+const HOUR: u64 = 60 * 60;
+
+#[test]
+fn listing_fee() {
+	let fees = FeeSchedule::default();
+
+	assert_eq!(fees.listing_fee(true, 500_000), 5_000);
+	assert_eq!(fees.listing_fee(true, 10_000_000), 200_000);
+	assert_eq!(fees.listing_fee(true, 200_000_000), 5_000_000);
+	assert_eq!(fees.listing_fee(false, 500_000), 25_000);
+}
+
+#[test]
+fn duration_fee() {
+	let fees = FeeSchedule::default();
+
+	assert_eq!(fees.duration_fee(Duration::from_secs(HOUR)), 20);
+	assert_eq!(fees.duration_fee(Duration::from_secs(6 * HOUR + 1)), 100);
+	assert_eq!(fees.duration_fee(Duration::from_secs(14 * 24 * HOUR)), 1200);
+}
+
+#[test]
+fn claim_tax() {
+	let fees = FeeSchedule::default();
+
+	assert_eq!(fees.claim_tax(1_000_000), 0);
+	assert_eq!(fees.claim_tax(2_000_000), 20_000);
+}
+
+#[test]
+fn proceeds() {
+	let fees = FeeSchedule::default();
+	let bin = fees.proceeds(&Listing::bin(2_000_000, Duration::from_secs(24 * HOUR)));
+
+	assert_eq!(bin, Proceeds { listing_fee: 20_000, duration_fee: 350, claim_tax: 20_000, net: 2_000_000 - 40_350 });
+	assert_eq!(bin.fees(), 40_350);
+
+	// The listing fee of an auction is charged on the starting bid, not the sale.
+	let auction = fees.proceeds(&Listing::auction(100_000, 2_000_000, Duration::from_secs(24 * HOUR)));
+
+	assert_eq!(auction.listing_fee, 5_000);
+	assert_eq!(auction.net, 2_000_000 - 5_000 - 350 - 20_000);
+
+	// At the same price, an auction pays a different listing fee to a BIN.
+	let auction = fees.proceeds(&Listing::auction(2_000_000, 2_000_000, Duration::from_secs(24 * HOUR)));
+
+	assert_ne!(auction, bin);
+	assert_eq!(auction.listing_fee, 100_000);
+}
+
+#[test]
+fn auction() {
+	let auction: Auction = serde_json::from_str(include_str!("../deserialize/auction/auction.json")).unwrap();
+	let listing = auction.listing();
+
+	assert!(!listing.bin);
+	assert_eq!(listing.price, 500);
+	assert_eq!(listing.sale_price, 575);
+	assert_eq!(listing.duration, Duration::from_secs(6 * HOUR));
+	assert_eq!(auction.proceeds(&FeeSchedule::default()).net, 575 - 25 - 45);
+}
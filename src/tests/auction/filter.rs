@@ -0,0 +1,106 @@
+use crate::objects::*;
+use crate::objects::auction::filter::*;
+use std::time::{Duration, UNIX_EPOCH};
+
+fn auction() -> Auction {
+	serde_json::from_str(include_str!("../deserialize/auction/auction.json")).unwrap()
+}
+
+#[test]
+fn item() {
+	let auction = auction();
+
+	assert!(AuctionFilter::name_contains("ice rune").matches(&auction));
+	assert!(AuctionFilter::extra_contains("SKULL ITEM").matches(&auction));
+	assert!(AuctionFilter::category(Category::Misc).matches(&auction));
+	assert!(AuctionFilter::tier(Rarity::Common, Rarity::Rare).matches(&auction));
+	assert!(!AuctionFilter::tier(Rarity::Rare, Rarity::Legendary).matches(&auction));
+}
+
+#[test]
+fn tier_range() {
+	let mut auction = auction();
+	let epic_to_mythic = AuctionFilter::tier(Rarity::Epic, Rarity::Mythic);
+
+	for tier in &[Rarity::Epic, Rarity::Legendary, Rarity::Mythic, Rarity::Artifact] {
+		auction.item.tier = *tier;
+		assert!(epic_to_mythic.matches(&auction), "{:?}", tier);
+	}
+	for tier in &[Rarity::Rare, Rarity::Divine, Rarity::Supreme, Rarity::Special, Rarity::VerySpecial] {
+		auction.item.tier = *tier;
+		assert!(!epic_to_mythic.matches(&auction), "{:?}", tier);
+	}
+
+	auction.item.tier = Rarity::Special;
+	assert!(AuctionFilter::tier(Rarity::Mythic, Rarity::VerySpecial).matches(&auction));
+}
+
+#[test]
+fn rarity_order() {
+	assert!(Rarity::Legendary < Rarity::Mythic);
+	assert!(Rarity::Mythic < Rarity::Artifact);
+	assert!(Rarity::Artifact < Rarity::Divine);
+	assert!(Rarity::Supreme < Rarity::Special);
+	assert!(Rarity::VerySpecial < Rarity::Unobtainable);
+}
+
+#[test]
+fn listing() {
+	let auction = auction();
+
+	assert!(AuctionFilter::price(575, 575).matches(&auction));
+	assert!(!AuctionFilter::price(0, 500).matches(&auction));
+	assert!(!AuctionFilter::bin().matches(&auction));
+	assert!(AuctionFilter::auctioneer(PartialProfile("f06e38d0cd634a4ea23306cef8b0bcee".to_string())).matches(&auction));
+}
+
+#[test]
+fn ending() {
+	let auction = auction();
+	let now = UNIX_EPOCH + Duration::from_millis(auction.end as u64 - 60_000);
+	let filter = AuctionFilter::ending_within(Duration::from_secs(120));
+
+	assert!(filter.matches_at(&auction, now));
+	assert!(!filter.matches_at(&auction, now - Duration::from_secs(120)));
+}
+
+#[test]
+fn combinators() {
+	let auction = auction();
+	let rune = AuctionFilter::name_contains("rune");
+	let book = AuctionFilter::name_contains("book");
+
+	assert!(AuctionFilter::default().matches(&auction));
+	assert!(rune.clone().and(!book.clone()).matches(&auction));
+	assert!(!rune.clone().and(book.clone()).matches(&auction));
+	assert!(book.clone().or(rune).matches(&auction));
+	assert!(!(!!book).matches(&auction));
+}
+
+#[test]
+fn on_match() {
+	let mut matched = vec![];
+	{
+		let mut f = AuctionFilter::name_contains("rune").on_match(|auction| {
+			matched.push(auction.uuid);
+			Ok(())
+		});
+
+		let mut other = auction();
+		other.item.name = "Hot Potato Book".to_string();
+
+		f(auction()).unwrap();
+		f(other).unwrap();
+	}
+
+	assert_eq!(matched, vec![auction().uuid]);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() {
+	let auction = auction();
+
+	assert!(AuctionFilter::name_matches(r"Rune I+$").unwrap().matches(&auction));
+	assert!(AuctionFilter::name_matches("(").is_err());
+}
@@ -0,0 +1,49 @@
+use super::{auctions, list_bins};
+use crate::objects::auction::index::*;
+
+#[test]
+fn key() {
+	let page = auctions();
+	let book = page.auctions.iter().find(|auction| auction.item.name == "Hot Potato Book").unwrap();
+
+	assert_eq!(book.item.key(), "HOT_POTATO_BOOK");
+}
+
+#[test]
+fn lowest_bin() {
+	let mut page = auctions();
+	list_bins(&mut page, "Hot Potato Book", &[90_000, 70_000, 80_000, 75_000]);
+
+	let index = AuctionIndex::build(page.last_update, &page.auctions);
+	let entry = index.get("HOT_POTATO_BOOK").unwrap();
+
+	assert_eq!(index.last_update(), 1579067131798);
+	assert_eq!(entry.listings, 27);
+	assert_eq!(entry.bin_listings, 4);
+	assert_eq!(entry.lowest_bin, Some(70_000));
+	assert_eq!(entry.second_lowest_bin, Some(75_000));
+	assert_eq!(entry.median_bin, Some(77_500));
+	assert_eq!(index.lowest_bin("HOT_POTATO_BOOK"), Some(70_000));
+}
+
+#[test]
+fn without_bins() {
+	let page = auctions();
+	let index = AuctionIndex::build(page.last_update, &page.auctions);
+	let entry = index.get("SUMMONING_EYE").unwrap();
+
+	assert_eq!(entry.listings, 21);
+	assert_eq!(entry.lowest_bin, None);
+	assert_eq!(entry.median_bin, None);
+}
+
+#[test]
+fn update() {
+	let page = auctions();
+	let mut index = AuctionIndex::build(page.last_update, &page.auctions);
+
+	assert!(!index.update(AuctionIndex::build(page.last_update, &[])));
+	assert!(!index.is_empty());
+	assert!(index.update(AuctionIndex::build(page.last_update + 1, &[])));
+	assert!(index.is_empty());
+}
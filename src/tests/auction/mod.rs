@@ -0,0 +1,32 @@
+use crate::Result;
+use crate::client::ApiBody;
+use crate::objects::GlobalAuctions;
+
+mod bids;
+mod fees;
+mod filter;
+mod index;
+mod search;
+mod sniper;
+mod sync;
+mod time;
+#[cfg(feature = "watch")]
+mod watch;
+
+/// The page of auctions from the deserialize fixtures.
+pub(super) fn auctions() -> GlobalAuctions {
+	let body: ApiBody<GlobalAuctions> = serde_json::from_str(include_str!("../deserialize/auction/auctions_body.json")).unwrap();
+	let res: Result<_> = body.into();
+
+	res.ok().unwrap()
+}
+
+/// Turns every listing of an item into a BIN at the given prices, in order.
+pub(super) fn list_bins(page: &mut GlobalAuctions, name: &str, prices: &[i64]) {
+	let listings = page.auctions.iter_mut().filter(|auction| auction.item.name == name);
+
+	for (auction, price) in listings.zip(prices) {
+		auction.bin = true;
+		auction.bids.starting = *price;
+	}
+}
\ No newline at end of file
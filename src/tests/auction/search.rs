@@ -0,0 +1,61 @@
+use super::auctions;
+use crate::objects::*;
+use crate::objects::auction::search::*;
+use crate::objects::auction::sync::*;
+
+#[test]
+fn tokens() {
+	assert_eq!(tokenize("◆ Ice Rune I"), vec!["ice", "rune", "i"]);
+	assert_eq!(tokenize(""), Vec::<String>::new());
+}
+
+#[test]
+fn plain_lore() {
+	let auction: Auction = serde_json::from_str(include_str!("../deserialize/auction/auction.json")).unwrap();
+
+	assert!(auction.item.plain_lore().starts_with("Requires level 8\nBows"));
+	assert!(!auction.item.plain_lore().contains('§'));
+	assert_eq!(strip_formatting("§d§l§ka§r §d§lMYTHIC ACCESSORY"), "a MYTHIC ACCESSORY");
+}
+
+#[test]
+fn search() {
+	let page = auctions();
+	let index = SearchIndex::build(&page.auctions);
+	let hits = index.search("hot potato book");
+
+	assert_eq!(index.len(), page.auctions.len());
+	assert!(hits.len() >= 27);
+	let top = page.auctions.iter().find(|auction| auction.uuid == hits[0].auction).unwrap();
+	assert_eq!(top.item.name, "Hot Potato Book");
+	assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+}
+
+#[test]
+fn prefix() {
+	let page = auctions();
+	let index = SearchIndex::build(&page.auctions);
+
+	assert_eq!(index.search("hot pot").len(), index.search("hot potato").len());
+	assert!(index.search("zzzzzz").is_empty());
+	assert!(index.search("").is_empty());
+}
+
+#[test]
+fn incremental() {
+	let page = auctions();
+	let mut sync = AuctionSync::new();
+	let mut index = SearchIndex::new();
+	index.apply(&sync.apply(page.last_update, page.auctions.clone()));
+
+	let eyes = index.search("summoning eye").len();
+	let remaining: Vec<Auction> = page.auctions.iter()
+		.filter(|auction| auction.item.name != "Summoning Eye")
+		.cloned()
+		.collect();
+	index.apply(&sync.apply(page.last_update + 1, remaining));
+
+	assert!(eyes >= 21);
+	assert!(index.search("summoning eye").len() < eyes);
+	assert_eq!(index.len(), sync.len());
+}
@@ -0,0 +1,70 @@
+use super::{auctions, list_bins};
+use crate::objects::*;
+use crate::objects::auction::index::*;
+use crate::objects::auction::sniper::*;
+use crate::objects::auction::sync::*;
+
+/// A page where the Hot Potato Books are BINs at the given prices, in order.
+fn listed(prices: &[i64]) -> GlobalAuctions {
+	let mut page = auctions();
+	list_bins(&mut page, "Hot Potato Book", prices);
+
+	page
+}
+
+fn book(page: &GlobalAuctions, price: i64) -> &Auction {
+	page.auctions.iter().find(|auction| auction.bin && auction.bids.starting == price).unwrap()
+}
+
+#[test]
+fn underpriced() {
+	let page = listed(&[50_000, 100_000, 110_000]);
+	let index = AuctionIndex::build(page.last_update, &page.auctions);
+	let snipe = Sniper::default().check(book(&page, 50_000), &index).unwrap();
+
+	assert_eq!(snipe.key, "HOT_POTATO_BOOK");
+	assert_eq!(snipe.reference_price, 100_000);
+	assert_eq!(snipe.expected_profit, 100_000 - 1000 - 1200 - 50_000);
+}
+
+#[test]
+fn fairly_priced() {
+	let page = listed(&[90_000, 100_000, 110_000]);
+	let index = AuctionIndex::build(page.last_update, &page.auctions);
+	let sniper = Sniper::default();
+
+	assert_eq!(sniper.check(book(&page, 90_000), &index), None);
+	assert_eq!(sniper.check(book(&page, 110_000), &index), None);
+}
+
+#[test]
+fn rules() {
+	let page = listed(&[50_000, 100_000, 110_000]);
+	let index = AuctionIndex::build(page.last_update, &page.auctions);
+	let auction = book(&page, 50_000);
+
+	let sniper = Sniper { excluded_items: vec!["HOT_POTATO_BOOK".to_string()], ..Sniper::default() };
+	assert_eq!(sniper.check(auction, &index), None);
+
+	let sniper = Sniper { min_profit: 60_000, ..Sniper::default() };
+	assert_eq!(sniper.check(auction, &index), None);
+
+	let sniper = Sniper { reference: Reference::MedianBin, ..Sniper::default() };
+	assert_eq!(sniper.check(auction, &index).unwrap().reference_price, 100_000);
+
+	let mut starred = auction.clone();
+	starred.item.name = "Hot Potato Book ✪".to_string();
+	assert_eq!(starred.item.modifiers(), vec![Modifier::Starred]);
+	assert_eq!(Sniper::default().check(&starred, &index), None);
+}
+
+#[test]
+fn scan() {
+	let page = listed(&[50_000, 100_000, 110_000]);
+	let index = AuctionIndex::build(page.last_update, &page.auctions);
+	let diff = AuctionSync::new().apply(page.last_update, page.auctions.clone());
+	let snipes = Sniper::default().scan(&diff, &index);
+
+	assert_eq!(snipes.len(), 1);
+	assert_eq!(snipes[0].price, 50_000);
+}
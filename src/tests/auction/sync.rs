@@ -0,0 +1,81 @@
+use super::auctions;
+use crate::objects::*;
+use crate::objects::auction::sync::*;
+
+#[test]
+fn initial() {
+	let page = auctions();
+	let mut sync = AuctionSync::new();
+
+	assert!(sync.is_stale(page.last_update));
+
+	let diff = sync.apply(page.last_update, page.auctions.clone());
+
+	assert_eq!(diff.added.len(), page.auctions.len());
+	assert!(diff.removed.is_empty());
+	assert!(diff.bid_changes.is_empty());
+	assert_eq!(sync.len(), page.auctions.len());
+	assert!(!sync.is_stale(page.last_update));
+}
+
+#[test]
+fn changes() {
+	let page = auctions();
+	let mut sync = AuctionSync::new();
+	sync.apply(page.last_update, page.auctions.clone());
+
+	let mut next = page.auctions.clone();
+	let ended = next.remove(0);
+	let listed = next.pop().unwrap();
+	let bid = Bid {
+		auction_id: next[0].uuid.clone(),
+		bidder: PartialProfile("be366506d9cd415687a9a1c90a888550".to_string()),
+		amount: next[0].bids.highest.max(next[0].bids.starting) + 100,
+		timestamp: 1579067200000,
+	};
+	let previous_highest = next[0].bids.highest;
+	next[0].bids.highest = bid.amount;
+	next[0].bids.bids.push(bid.clone());
+
+	// Scanned without the last auction, which is then relisted.
+	sync.apply(page.last_update + 1, next.clone());
+	next.push(listed.clone());
+	let diff = sync.apply(page.last_update + 2, next.clone());
+
+	assert_eq!(diff.last_update, page.last_update + 2);
+	assert_eq!(diff.added, vec![listed]);
+	assert!(diff.removed.is_empty());
+	assert!(diff.bid_changes.is_empty());
+	assert_eq!(sync.get(&ended.uuid), None);
+
+	let mut sync = AuctionSync::new();
+	sync.apply(page.last_update, page.auctions.clone());
+	let diff = sync.apply(page.last_update + 1, next);
+
+	assert_eq!(diff.removed, vec![ended]);
+	assert_eq!(diff.bid_changes.len(), 1);
+	assert_eq!(diff.bid_changes[0].previous_highest, previous_highest);
+	assert_eq!(diff.bid_changes[0].new_bids, vec![bid]);
+}
+
+#[test]
+fn duplicates() {
+	let page = auctions();
+	let mut sync = AuctionSync::new();
+	sync.apply(page.last_update, page.auctions.clone());
+
+	// A page shifted between requests, so its first auction is scanned twice.
+	let mut next = page.auctions.clone();
+	next.push(next[0].clone());
+	let diff = sync.apply(page.last_update + 1, next);
+
+	assert!(diff.is_empty());
+	assert_eq!(sync.len(), page.auctions.len());
+
+	let mut sync = AuctionSync::new();
+	let mut first = page.auctions.clone();
+	first.push(first[0].clone());
+	let diff = sync.apply(page.last_update, first);
+
+	assert_eq!(diff.added.len(), page.auctions.len());
+}
@@ -0,0 +1,53 @@
+use crate::objects::*;
+use crate::objects::auction::time::from_millis;
+use std::time::{Duration, UNIX_EPOCH};
+
+fn auction() -> Auction {
+	serde_json::from_str(include_str!("../deserialize/auction/auction.json")).unwrap()
+}
+
+#[test]
+fn conversion() {
+	assert_eq!(from_millis(1500), UNIX_EPOCH + Duration::from_millis(1500));
+	assert_eq!(from_millis(-1500), UNIX_EPOCH - Duration::from_millis(1500));
+}
+
+#[test]
+fn accessors() {
+	let auction = auction();
+
+	assert_eq!(auction.start_time(), UNIX_EPOCH + Duration::from_millis(1579054579940));
+	assert_eq!(auction.end_time(), UNIX_EPOCH + Duration::from_millis(1579076179940));
+	assert_eq!(auction.bids.bids[0].time(), UNIX_EPOCH + Duration::from_millis(1579060663571));
+}
+
+#[test]
+fn remaining() {
+	let auction = auction();
+	let now = auction.end_time() - Duration::from_secs(90);
+
+	assert_eq!(auction.time_remaining(now), Duration::from_secs(90));
+	assert!(!auction.is_ended(now));
+	assert_eq!(auction.time_remaining(auction.end_time() + Duration::from_secs(1)), Duration::from_secs(0));
+	assert!(auction.is_ended(auction.end_time()));
+}
+
+#[test]
+fn ending_within() {
+	let auction = auction();
+	let now = auction.end_time() - Duration::from_secs(90);
+
+	assert!(auction.ending_within(Duration::from_secs(90), now));
+	assert!(auction.ending_within(Duration::from_secs(120), now));
+	assert!(!auction.ending_within(Duration::from_secs(60), now));
+	assert!(auction.ending_within(Duration::from_secs(0), auction.end_time() + Duration::from_secs(1)));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn datetime() {
+	let auction = auction();
+
+	assert_eq!(auction.end_datetime().timestamp_millis(), auction.end);
+	assert_eq!(auction.bids.bids[0].datetime().timestamp_millis(), auction.bids.bids[0].timestamp);
+}
@@ -0,0 +1,167 @@
+use super::auctions;
+use crate::objects::*;
+use crate::objects::auction::filter::*;
+use crate::objects::auction::sync::*;
+use crate::objects::auction::watch::*;
+use std::time::Duration;
+
+fn watching(page: &GlobalAuctions) -> (Watchlist, Auction) {
+	let mut watchlist = Watchlist::new(64, Duration::from_secs(5 * 60));
+	let auction = page.auctions[0].clone();
+	watchlist.watch(Watch::Auction(auction.uuid.clone()));
+
+	(watchlist, auction)
+}
+
+#[test]
+fn listed_and_ended() {
+	let page = auctions();
+	let (mut watchlist, auction) = watching(&page);
+	let mut receiver = watchlist.subscribe();
+	let mut sync = AuctionSync::new();
+
+	let diff = sync.apply(page.last_update, page.auctions.clone());
+	let events = watchlist.process(&diff, &sync, auction.start_time());
+
+	assert_eq!(events, vec![WatchEvent::Listed(auction.clone())]);
+	assert_eq!(receiver.try_recv().unwrap(), WatchEvent::Listed(auction.clone()));
+
+	let diff = sync.apply(page.last_update + 1, page.auctions[1..].to_vec());
+	let events = watchlist.process(&diff, &sync, auction.end_time());
+
+	assert_eq!(events, vec![WatchEvent::Ended(auction.clone())]);
+	assert_eq!(watchlist.unclaimed().collect::<Vec<_>>(), vec![&auction.uuid]);
+	assert_eq!(watchlist.process_claim(&auction.uuid, Some(&auction)), None);
+	assert_eq!(watchlist.process_claim(&auction.uuid, None), Some(WatchEvent::Claimed(auction.clone())));
+	assert_eq!(watchlist.unclaimed().count(), 0);
+}
+
+#[test]
+fn bids() {
+	let page = auctions();
+	let (mut watchlist, auction) = watching(&page);
+	let mut sync = AuctionSync::new();
+	sync.apply(page.last_update, page.auctions.clone());
+
+	let first = Bid {
+		auction_id: auction.uuid.clone(),
+		bidder: PartialProfile("a".to_string()),
+		amount: auction.bids.starting.max(auction.bids.highest) + 10,
+		timestamp: auction.start + 1000,
+	};
+	let second = Bid {
+		bidder: PartialProfile("b".to_string()),
+		amount: first.amount + 10,
+		timestamp: first.timestamp + 1000,
+		..first.clone()
+	};
+	let mut next = page.auctions.clone();
+	next[0].bids.bids.push(first.clone());
+	next[0].bids.bids.push(second.clone());
+	next[0].bids.highest = second.amount;
+
+	let diff = sync.apply(page.last_update + 1, next.clone());
+	let events = watchlist.process(&diff, &sync, auction.start_time());
+
+	assert!(events.contains(&WatchEvent::NewBid { auction: next[0].clone(), bid: first.clone() }));
+	assert!(events.contains(&WatchEvent::NewBid { auction: next[0].clone(), bid: second.clone() }));
+	assert!(events.contains(&WatchEvent::Outbid { auction: next[0].clone(), previous: first, winning: second }));
+}
+
+#[test]
+fn ending_soon() {
+	let page = auctions();
+	let (mut watchlist, auction) = watching(&page);
+	let mut sync = AuctionSync::new();
+	let diff = sync.apply(page.last_update, page.auctions.clone());
+	let soon = auction.projected_end_time() - Duration::from_secs(60);
+
+	let events = watchlist.process(&diff, &sync, soon);
+	assert!(events.contains(&WatchEvent::EndingSoon(auction.clone())));
+
+	// Only sent once.
+	let events = watchlist.process(&AuctionDiff::default(), &sync, soon);
+	assert!(events.is_empty());
+}
+
+#[test]
+fn unwatch() {
+	let page = auctions();
+	let mut watchlist = Watchlist::new(64, Duration::from_secs(60));
+	let id = watchlist.watch(Watch::Auctioneer(page.auctions[0].auctioneer.clone()));
+
+	assert!(watchlist.is_watched(&page.auctions[0]));
+	assert!(watchlist.unwatch(id).is_some());
+	assert!(!watchlist.is_watched(&page.auctions[0]));
+}
+
+#[test]
+fn broad_watches_leave_claims_alone() {
+	let page = auctions();
+	let mut watchlist = Watchlist::new(64, Duration::from_secs(60));
+	watchlist.watch(Watch::Filter(AuctionFilter::Any));
+	watchlist.watch(Watch::Auctioneer(page.auctions[0].auctioneer.clone()));
+	let mut sync = AuctionSync::new();
+	sync.apply(page.last_update, page.auctions.clone());
+
+	let diff = sync.apply(page.last_update + 1, vec![]);
+	let events = watchlist.process(&diff, &sync, page.auctions[0].end_time());
+
+	assert_eq!(events.len(), page.auctions.len());
+	assert_eq!(watchlist.unclaimed().count(), 0);
+}
+
+#[test]
+fn unwatch_drops_claim() {
+	let page = auctions();
+	let auction = page.auctions[0].clone();
+	let mut watchlist = Watchlist::new(64, Duration::from_secs(60));
+	let first = watchlist.watch(Watch::Auction(auction.uuid.clone()));
+	let second = watchlist.watch(Watch::Auction(auction.uuid.clone()));
+	let mut sync = AuctionSync::new();
+	sync.apply(page.last_update, page.auctions.clone());
+
+	let diff = sync.apply(page.last_update + 1, page.auctions[1..].to_vec());
+	watchlist.process(&diff, &sync, auction.end_time());
+
+	// Still watched through the second watch.
+	watchlist.unwatch(first);
+	assert_eq!(watchlist.unclaimed().count(), 1);
+
+	watchlist.unwatch(second);
+	assert_eq!(watchlist.unclaimed().count(), 0);
+}
+
+#[test]
+fn watched_after_listing() {
+	let page = auctions();
+	let auction = page.auctions[0].clone();
+	let mut watchlist = Watchlist::new(64, Duration::from_secs(5 * 60));
+	let mut sync = AuctionSync::new();
+	let diff = sync.apply(page.last_update, page.auctions.clone());
+	let soon = auction.projected_end_time() - Duration::from_secs(60);
+
+	assert!(watchlist.process(&diff, &sync, auction.start_time()).is_empty());
+
+	// Nothing changed, but the new watch matches an auction already in the snapshot.
+	watchlist.watch(Watch::Auction(auction.uuid.clone()));
+	let events = watchlist.process(&AuctionDiff::default(), &sync, soon);
+	assert_eq!(events, vec![WatchEvent::EndingSoon(auction)]);
+}
+
+#[test]
+fn unwatched_before_ending() {
+	let page = auctions();
+	let auction = page.auctions[0].clone();
+	let mut watchlist = Watchlist::new(64, Duration::from_secs(5 * 60));
+	let id = watchlist.watch(Watch::Auctioneer(auction.auctioneer.clone()));
+	let mut sync = AuctionSync::new();
+	let diff = sync.apply(page.last_update, page.auctions.clone());
+	watchlist.process(&diff, &sync, auction.start_time());
+
+	watchlist.unwatch(id);
+	let soon = auction.projected_end_time() - Duration::from_secs(60);
+	let events = watchlist.process(&AuctionDiff::default(), &sync, soon);
+
+	assert!(!events.contains(&WatchEvent::EndingSoon(auction)));
+}
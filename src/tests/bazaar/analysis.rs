@@ -0,0 +1,62 @@
+use super::product;
+use crate::objects::bazaar::analysis::*;
+use std::collections::HashMap;
+
+#[test]
+fn tax() {
+	assert_eq!(BazaarTax::default().rate, 0.0125);
+	assert_eq!(BazaarTax::with_flipper_upgrades(2).rate, 0.01);
+	assert_eq!(BazaarTax::new(0.01).net(1000.0), 990.0);
+}
+
+#[test]
+fn evaluate() {
+	let tax = BazaarTax::default();
+	let flip = Flip::evaluate(&product(), &tax).unwrap();
+
+	assert_eq!(flip.product_id, "ENCHANTED_DIAMOND");
+	assert_eq!(flip.buy_price, 1205.3);
+	assert_eq!(flip.sell_price, 1250.0);
+	assert!(flip.margin > 0.0);
+	assert_eq!(flip.margin, tax.net(flip.sell_price) - flip.buy_price);
+	assert_eq!(flip.hourly_volume, 980000.0 / 168.0);
+	assert_eq!(flip.hourly_profit, flip.margin * flip.hourly_volume);
+}
+
+#[test]
+fn empty_book() {
+	let mut product = product();
+	product.live_data.sell_summary.clear();
+
+	assert_eq!(Flip::evaluate(&product, &BazaarTax::default()), None);
+}
+
+#[test]
+fn free_buy_orders() {
+	let mut product = product();
+	for order in product.live_data.sell_summary.iter_mut() {
+		order.price_per_unit = 0.0;
+	}
+
+	assert_eq!(Flip::evaluate(&product, &BazaarTax::default()), None);
+}
+
+#[test]
+fn ranking() {
+	let tax = BazaarTax::default();
+	let quiet = product();
+	let mut busy = product();
+	busy.product_id = "ENCHANTED_DIAMOND_BLOCK".to_string();
+	busy.live_data.quick_status.buy_moving_week *= 2.0;
+	busy.live_data.quick_status.sell_moving_week *= 2.0;
+
+	let mut listing = HashMap::new();
+	listing.insert(quiet.product_id.clone(), quiet);
+	listing.insert(busy.product_id.clone(), busy);
+
+	let flips = rank_flips(&listing, &tax);
+
+	assert_eq!(flips.len(), 2);
+	assert_eq!(flips[0].product_id, "ENCHANTED_DIAMOND_BLOCK");
+	assert!(flips[0].hourly_profit >= flips[1].hourly_profit);
+}
@@ -0,0 +1,61 @@
+use super::product;
+
+#[test]
+fn instant_buy() {
+	let fill = product().live_data.instant_buy(300).unwrap();
+
+	assert_eq!(fill.filled, 300);
+	assert_eq!(fill.unfilled, 0);
+	assert_eq!(fill.total, 100.0 * 1250.0 + 200.0 * 1260.5);
+	assert_eq!(fill.top_price, 1250.0);
+	assert_eq!(fill.worst_price, 1260.5);
+	assert!(fill.slippage > 0.0);
+}
+
+#[test]
+fn instant_sell() {
+	let fill = product().live_data.instant_sell(64).unwrap();
+
+	assert_eq!(fill.filled, 64);
+	assert_eq!(fill.average_price, 1205.3);
+	assert_eq!(fill.slippage, 0.0);
+}
+
+#[test]
+fn exhausted() {
+	let fill = product().live_data.instant_buy(10_000).unwrap();
+
+	assert_eq!(fill.filled, 2640);
+	assert_eq!(fill.unfilled, 10_000 - 2640);
+	assert_eq!(fill.worst_price, 1400.0);
+}
+
+#[test]
+fn empty() {
+	let mut data = product().live_data;
+	data.buy_summary.clear();
+
+	assert_eq!(data.instant_buy(1), None);
+	assert_eq!(data.instant_sell(0), None);
+	assert!(data.buy_depth().is_empty());
+	assert_eq!(data.buy_amount_within(0.1), 0);
+}
+
+#[test]
+fn depth() {
+	let data = product().live_data;
+	let curve = data.sell_depth();
+
+	assert_eq!(curve.len(), 4);
+	assert_eq!(curve[1].amount, 384);
+	assert_eq!(curve[3].amount, 3432);
+	assert_eq!(curve[3].price, 1150.0);
+}
+
+#[test]
+fn amount_within() {
+	let data = product().live_data;
+
+	assert_eq!(data.buy_amount_within(0.01), 500);
+	assert_eq!(data.sell_amount_within(0.02), 1384);
+}
@@ -0,0 +1,62 @@
+use crate::objects::*;
+use super::product;
+use crate::objects::bazaar::diff::*;
+use std::collections::HashMap;
+
+#[test]
+fn unchanged() {
+	let diff = ProductDiff::between(&product(), &product());
+
+	assert!(diff.is_empty());
+}
+
+#[test]
+fn levels() {
+	let old = product();
+	let mut new = product();
+	new.live_data.buy_summary.remove(0);
+	new.live_data.sell_summary.insert(0, Order { amount: 10, price_per_unit: 1210.0, orders: 1 });
+	new.live_data.sell_summary[2].amount -= 20;
+	new.live_data.quick_status.buy_moving_week += 100.0;
+
+	let diff = ProductDiff::between(&old, &new);
+
+	assert_eq!(diff.offers_added, vec![]);
+	assert_eq!(diff.offers_removed, vec![LevelChange { price: 1250.0, amount: -100, orders: -1 }]);
+	assert_eq!(diff.bids_added, vec![LevelChange { price: 1210.0, amount: 10, orders: 1 }]);
+	assert_eq!(diff.bids_removed, vec![LevelChange { price: 1201.1, amount: -20, orders: 0 }]);
+	assert_eq!(diff.instant_buys, 100.0);
+	assert_eq!(diff.instant_sells, 0.0);
+}
+
+#[test]
+fn truncated_book() {
+	let mut old = product();
+	old.live_data.buy_summary = (0..30)
+		.map(|i| Order { amount: 1, price_per_unit: 100.0 + i as f64, orders: 1 })
+		.collect();
+	let mut new = old.clone();
+	// An offer at the top pushes the most expensive level out of view.
+	new.live_data.buy_summary.insert(0, Order { amount: 1, price_per_unit: 99.0, orders: 1 });
+	new.live_data.buy_summary.pop();
+
+	let diff = ProductDiff::between(&old, &new);
+
+	assert_eq!(diff.offers_added, vec![LevelChange { price: 99.0, amount: 1, orders: 1 }]);
+	assert_eq!(diff.offers_removed, vec![]);
+}
+
+#[test]
+fn snapshots() {
+	let mut old = HashMap::new();
+	let mut new = HashMap::new();
+	old.insert("ENCHANTED_DIAMOND".to_string(), product());
+	new.insert("ENCHANTED_DIAMOND".to_string(), product());
+	new.insert("DIAMOND".to_string(), product());
+
+	let diff = BazaarDiff::between(&old, &new);
+
+	assert_eq!(diff.products.len(), 1);
+	assert!(diff.get("ENCHANTED_DIAMOND").unwrap().is_empty());
+	assert_eq!(diff.get("DIAMOND"), None);
+}
@@ -0,0 +1,88 @@
+use crate::objects::*;
+use super::product;
+use crate::objects::bazaar::history::*;
+
+fn status(buy_price: f32, sell_price: f32) -> QuickStatus {
+	let product = product();
+
+	QuickStatus {
+		buy_price,
+		sell_price,
+		..product.live_data.quick_status
+	}
+}
+
+#[test]
+fn interval() {
+	assert_eq!(Interval::MINUTE.floor(61_000), 60_000);
+	assert_eq!(Interval::HOUR.floor(3_599_999), 0);
+	assert_eq!(Interval::FIVE_MINUTES.floor(-1), -300_000);
+}
+
+#[test]
+fn positive_interval() {
+	assert_eq!(Interval::from_millis(1000).unwrap().millis(), 1000);
+	assert_eq!(Interval::from_millis(0), None);
+	assert_eq!(Interval::from_millis(-60_000), None);
+
+	assert_eq!(serde_json::from_str::<Interval>("60000").unwrap(), Interval::MINUTE);
+	assert!(serde_json::from_str::<Interval>("0").is_err());
+	assert_eq!(serde_json::to_string(&Interval::HOUR).unwrap(), "3600000");
+}
+
+#[test]
+fn candles() {
+	let mut history = PriceHistory::new();
+	history.record_status(10_000, &status(10.0, 9.0));
+	history.record_status(50_000, &status(14.0, 9.5));
+	// Recorded out of order, but sampled before the previous one.
+	history.record_status(30_000, &status(8.0, 8.5));
+	history.record_status(70_000, &status(12.0, 11.0));
+
+	let candles = history.candles("ENCHANTED_DIAMOND", Interval::MINUTE, PriceKind::InstantBuy);
+
+	assert_eq!(candles, vec![
+		Candle { start: 0, open: 10.0, high: 14.0, low: 8.0, close: 14.0, samples: 3 },
+		Candle { start: 60_000, open: 12.0, high: 12.0, low: 12.0, close: 12.0, samples: 1 },
+	]);
+
+	let candles = history.candles("ENCHANTED_DIAMOND", Interval::HOUR, PriceKind::InstantSell);
+
+	assert_eq!(candles.len(), 1);
+	assert_eq!(candles[0].low, 8.5);
+	assert_eq!(candles[0].close, 11.0);
+}
+
+#[test]
+fn historic() {
+	let mut history = PriceHistory::new();
+	let historic = Historic {
+		product_id: "ENCHANTED_DIAMOND".to_string(),
+		timestamp: 1000,
+		market_demand: 0.0,
+		market_supply: 0.0,
+		buy_coins: 1000.0,
+		buy_volume: 10.0,
+		instant_buys: 0.0,
+		sell_coins: 2200.0,
+		sell_volume: 20.0,
+		instant_sells: 0.0,
+	};
+	history.record_historic(&historic);
+	history.record_historic(&Historic { sell_volume: 0.0, ..historic.clone() });
+
+	assert_eq!(history.samples("ENCHANTED_DIAMOND"), &[Sample { timestamp: 1000, instant_buy: 110.0, instant_sell: 100.0 }]);
+}
+
+#[test]
+fn prune() {
+	let mut history = PriceHistory::new();
+	history.record_status(1000, &status(1.0, 1.0));
+	history.record_status(2000, &status(2.0, 2.0));
+
+	history.prune(1500);
+	assert_eq!(history.samples("ENCHANTED_DIAMOND").len(), 1);
+
+	history.prune(3000);
+	assert!(history.samples("ENCHANTED_DIAMOND").is_empty());
+}
@@ -0,0 +1,12 @@
+use crate::objects::Product;
+
+mod analysis;
+mod depth;
+mod diff;
+mod history;
+mod quotes;
+
+/// The enchanted diamond product, from the deserialize fixtures.
+fn product() -> Product {
+	serde_json::from_str(include_str!("../deserialize/bazaar/product.json")).unwrap()
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use super::product;
+
+#[test]
+fn best_prices() {
+	let data = product().live_data;
+
+	assert_eq!(data.best_bid(), Some(1205.3));
+	assert_eq!(data.best_ask(), Some(1250.0));
+	assert_eq!(data.spread(), Some(1250.0 - 1205.3));
+	assert_eq!(data.mid_price(), Some((1250.0 + 1205.3) / 2.0));
+}
+
+#[test]
+fn empty_book() {
+	let mut data = product().live_data;
+	data.sell_summary.clear();
+
+	assert_eq!(data.best_bid(), None);
+	assert_eq!(data.best_ask(), Some(1250.0));
+	assert_eq!(data.spread(), None);
+	assert_eq!(data.mid_price(), None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_wrappers() {
+	let mut data = product().live_data;
+
+	assert_eq!(data.top_buy(), 1205.3);
+	assert_eq!(data.top_sell(), 1250.0);
+
+	data.sell_summary.clear();
+	data.buy_summary.clear();
+
+	assert_eq!(data.top_buy(), f64::NEG_INFINITY);
+	assert_eq!(data.top_sell(), f64::INFINITY);
+}
@@ -29,7 +29,7 @@ fn searched_auctions() {
 fn auction() {
 	let auction: Auction = serde_json::from_str(include_str!("deserialize/auction/auction.json")).unwrap();
 
-	assert_eq!(auction.uuid, "a1767fbbac3c48189c9e05a24b27f9bc");
+	assert_eq!(auction.uuid, PartialAuction("a1767fbbac3c48189c9e05a24b27f9bc".to_string()));
 	assert_eq!(auction.auctioneer, PartialProfile("f06e38d0cd634a4ea23306cef8b0bcee".to_string()));
 	assert_eq!(auction.start, 1579054579940);
 	assert_eq!(auction.end, 1579076179940);
@@ -48,7 +48,7 @@ fn item() {
 	let item: Item = serde_json::from_str(include_str!("deserialize/item/item.json")).unwrap();
 
 	assert_eq!(item.name, "◆ Ice Rune I");
-	assert_eq!(item.category, "misc");
+	assert_eq!(item.category, Category::Misc);
 	assert_eq!(item.extra, "◆ Ice Rune I Skull Item");
 	assert_eq!(item.lore, "§8Requires level 8\n§8Bows\n\n§7Your arrows are icy cold!\n\n§7Apply this rune to bows or fuse\n§7two together at the Runic\n§7Pedestal!\n\n§9§lRARE");
 	assert_eq!(item.tier, Rarity::Uncommon);
@@ -70,7 +70,7 @@ fn count() {
 	let mut item: Item = serde_json::from_str(include_str!("deserialize/item/item.json")).unwrap();
 
 	assert_eq!(item.count, None);
-	assert_eq!(item.count(), 10);
+	assert_eq!(item.count(), Some(10));
 	assert_eq!(item.count, Some(10));
 }
 
@@ -103,6 +103,174 @@ fn bid() {
 	assert_eq!(bid.timestamp, 1579060663571);
 }
 
+#[test]
+fn category() {
+	let category: Category = serde_json::from_str("\"weapon\"").unwrap();
+	assert_eq!(category, Category::Weapon);
+
+	let category: Category = serde_json::from_str("\"pets\"").unwrap();
+	assert_eq!(category, Category::Unknown("pets".to_string()));
+	assert_eq!(serde_json::to_string(&category).unwrap(), "\"pets\"");
+	assert_eq!(Category::Consumables.to_string(), "consumables");
+}
+
+#[test]
+fn product() {
+	let product: Product = serde_json::from_str(include_str!("deserialize/bazaar/product.json")).unwrap();
+
+	assert_eq!(product.product_id, "ENCHANTED_DIAMOND");
+	assert_eq!(product.live_data.buy_summary.len(), 4);
+	assert_eq!(product.live_data.quick_status.sell_orders, 10.0);
+}
+
+#[test]
+fn items_resource() {
+	let body: ApiBody<ItemsResource> = serde_json::from_str(include_str!("deserialize/resources/items.json")).unwrap();
+	let res: Result<_> = body.into();
+	let items = res.unwrap();
+
+	assert_eq!(items.items.len(), 4);
+	assert_eq!(items.name("ENCHANTED_DIAMOND"), Some("Enchanted Diamond"));
+	assert_eq!(items.npc_sell_price("STONE"), Some(1.0));
+	assert_eq!(items.npc_sell_price("VERY_SPECIAL_HAT"), None);
+
+	let aotd = items.get("ASPECT_OF_THE_DRAGON").unwrap();
+	assert_eq!(aotd.tier, Some(Rarity::Legendary));
+	assert_eq!(aotd.stats["DAMAGE"], 225.0);
+	assert_eq!(items.get("VERY_SPECIAL_HAT").unwrap().tier, Some(Rarity::VerySpecial));
+	assert_eq!(items.get("STONE").unwrap().tier, None);
+}
+
+#[test]
+fn collections_resource() {
+	let collections: CollectionsResource = serde_json::from_str(include_str!("deserialize/resources/collections.json")).unwrap();
+
+	let diamond = collections.get("DIAMOND").unwrap();
+	assert_eq!(diamond.max_tiers, 3);
+	assert_eq!(diamond.tiers[1].unlocks.len(), 2);
+	assert_eq!(diamond.tier_for(0), 0);
+	assert_eq!(diamond.tier_for(120), 2);
+	assert_eq!(diamond.tier_for(10_000), 3);
+	assert!(collections.get("COBBLESTONE").is_none());
+}
+
+#[test]
+fn skills_resource() {
+	let skills: SkillsResource = serde_json::from_str(include_str!("deserialize/resources/skills.json")).unwrap();
+
+	let farming = &skills.skills["FARMING"];
+	assert_eq!(farming.max_level, 3);
+	assert_eq!(farming.xp_for_level(0), Some(0.0));
+	assert_eq!(farming.xp_for_level(2), Some(175.0));
+	assert_eq!(farming.xp_for_level(4), None);
+}
+
+#[test]
+fn election_resource() {
+	let body: ApiBody<ElectionResource> = serde_json::from_str(include_str!("deserialize/resources/election.json")).unwrap();
+	let res: Result<_> = body.into();
+	let election = res.unwrap();
+
+	assert_eq!(election.mayor.name, "Derpy");
+	assert_eq!(election.active_perks().len(), 3);
+	assert!(election.has_perk("quad taxes!!!"));
+	assert!(election.has_perk("Slashed Pricing"));
+	assert_eq!(election.mayor.election.leader().unwrap().name, "Derpy");
+
+	let current = election.current.unwrap();
+	assert_eq!(current.year, 313);
+	assert_eq!(current.total_votes(), 4600);
+	assert_eq!(current.leader().unwrap().key, "mining");
+}
+
+#[test]
+fn news() {
+	let body: ApiBody<News> = serde_json::from_str(include_str!("deserialize/events/news.json")).unwrap();
+	let res: Result<_> = body.into();
+	let news = res.unwrap();
+
+	assert_eq!(news.items.len(), 2);
+	assert_eq!(news.items[0].title, "SkyBlock v0.19.3");
+	assert_eq!(news.items[1].item.material, "PAINTING");
+}
+
+#[test]
+fn firesales() {
+	let body: ApiBody<FireSales> = serde_json::from_str(include_str!("deserialize/events/firesales.json")).unwrap();
+	let res: Result<_> = body.into();
+	let sales = res.unwrap();
+
+	assert_eq!(sales.sales[0].price, 650);
+	assert_eq!(sales.sales[1].amount, 2500);
+
+	let now = sales.sales[0].start_time() + std::time::Duration::from_secs(60);
+	let active: Vec<_> = sales.active(now).map(|sale| sale.item_id.as_str()).collect();
+	assert_eq!(active, vec!["PET_SKIN_ENDERMAN_SLAYER"]);
+	assert!(!sales.sales[0].is_active(sales.sales[0].end_time()));
+}
+
+#[test]
+fn bingo() {
+	let body: ApiBody<BingoPlayer> = serde_json::from_str(include_str!("deserialize/events/bingo.json")).unwrap();
+	let res: Result<_> = body.into();
+	let player = res.unwrap();
+	let card: BingoCard = serde_json::from_str(include_str!("deserialize/events/bingo_card.json")).unwrap();
+
+	assert_eq!(card.goals[3].tiers.as_ref().unwrap().len(), 5);
+	assert_eq!(card.goals[0].required_amount, Some(10000));
+
+	let event = player.event(card.id).unwrap();
+	assert!(event.is_completed("collect_wheat"));
+	assert_eq!(card.completion(event), 0.25);
+	assert_eq!(card.remaining(event).count(), 3);
+	assert_eq!(card.completion(player.event(21).unwrap()), 0.75);
+	assert!(player.event(23).is_none());
+}
+
+#[test]
+fn museum() {
+	let body: ApiBody<Museum> = serde_json::from_str(include_str!("deserialize/profile/museum.json")).unwrap();
+	let res: Result<_> = body.into();
+	let museum = res.unwrap();
+
+	let member = &museum.members["be366506d9cd415687a9a1c90a888550"];
+	assert_eq!(member.value, 4520000);
+	assert!(member.items["RUNE"].borrowing);
+	assert_eq!(member.items["ASPECT_OF_THE_DRAGON"].featured_slot.as_deref(), Some("sword"));
+	assert_eq!(member.donations()[0].donated_time, 1689900000000);
+	assert!(museum.members["f06e38d0cd634a4ea23306cef8b0bcee"].items.is_empty());
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn museum_items() {
+	let museum: Museum = serde_json::from_str(include_str!("deserialize/profile/museum.json")).unwrap();
+	let member = &museum.members["be366506d9cd415687a9a1c90a888550"];
+
+	let items = member.donated_items().unwrap();
+	assert_eq!(items.len(), 3);
+	assert_eq!(items[0].count, 10);
+	assert_eq!(items[0].donated_time, 1689900000000);
+	assert_eq!(items[2].id, "ASPECT_OF_THE_DRAGON");
+}
+
+#[test]
+fn garden() {
+	let garden: Garden = serde_json::from_str::<serde_json::Value>(include_str!("deserialize/profile/garden.json"))
+		.map(|body| serde_json::from_value(body["garden"].clone()).unwrap())
+		.unwrap();
+
+	assert_eq!(garden.unlocked_plots.len(), 5);
+	assert!(garden.is_plot_unlocked("intermediate_1"));
+	assert!(!garden.is_plot_unlocked("advanced_1"));
+	assert_eq!(garden.crop_upgrade("WHEAT"), 9);
+	assert_eq!(garden.crop_upgrade("CACTUS"), 0);
+	assert_eq!(garden.visitors.total_visits(), 18);
+	assert_eq!(garden.visitors.completion_rate("jacob"), Some(0.75));
+	assert_eq!(garden.visitors.completion_rate("liam"), Some(0.0));
+	assert_eq!(garden.visitors.completion_rate("maeve"), None);
+}
+
 #[test]
 fn error_body() {
 	let body: ApiBody<Auction> = serde_json::from_str(include_str!("deserialize/api_error.json")).unwrap();
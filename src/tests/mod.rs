@@ -1,2 +1,38 @@
+use crate::objects::PriceSource;
+use std::collections::HashMap;
+
+mod auction;
+mod bazaar;
 mod deserialize;
-mod http;
\ No newline at end of file
+mod http;
+mod pricing;
+mod profile;
+mod recipes;
+
+/// Fixed buy and sell prices, keyed by item ID.
+pub(super) struct Prices(HashMap<&'static str, (f64, f64)>);
+
+impl Prices {
+	pub(super) fn new(prices: &[(&'static str, (f64, f64))]) -> Prices {
+		Prices(prices.iter().copied().collect())
+	}
+
+	/// Prices which are the same to buy and sell at.
+	pub(super) fn flat(prices: &[(&'static str, f64)]) -> Prices {
+		Prices(prices.iter().map(|(id, price)| (*id, (*price, *price))).collect())
+	}
+}
+
+impl PriceSource for Prices {
+	fn buy_price(&self, id: &str) -> Option<f64> {
+		self.0.get(id).map(|(buy, _)| *buy)
+	}
+
+	fn sell_price(&self, id: &str) -> Option<f64> {
+		self.0.get(id).map(|(_, sell)| *sell)
+	}
+
+	fn confidence(&self, id: &str) -> f64 {
+		if self.0.contains_key(id) { 1.0 } else { 0.0 }
+	}
+}
\ No newline at end of file
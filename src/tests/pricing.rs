@@ -0,0 +1,88 @@
+use super::auction::{auctions, list_bins};
+use crate::objects::*;
+use crate::objects::auction::index::AuctionIndex;
+use std::collections::HashMap;
+
+fn bazaar() -> HashMap<String, Product> {
+	let product: Product = serde_json::from_str(include_str!("deserialize/bazaar/product.json")).unwrap();
+
+	vec![(product.product_id.clone(), product)].into_iter().collect()
+}
+
+fn index() -> AuctionIndex {
+	let mut page = auctions();
+	list_bins(&mut page, "Hot Potato Book", &[90_000, 70_000, 80_000, 75_000]);
+
+	AuctionIndex::build(page.last_update, &page.auctions)
+}
+
+fn items() -> ItemsResource {
+	serde_json::from_str(include_str!("deserialize/resources/items.json")).unwrap()
+}
+
+#[test]
+fn bazaar_prices() {
+	let bazaar = bazaar();
+
+	assert_eq!(bazaar.buy_price("ENCHANTED_DIAMOND"), Some(1250.0));
+	assert_eq!(bazaar.sell_price("ENCHANTED_DIAMOND"), Some(1205.3));
+	assert_eq!(bazaar.confidence("ENCHANTED_DIAMOND"), 980_000.0 / 990_000.0);
+	assert_eq!(bazaar.sell_price("HOT_POTATO_BOOK"), None);
+	assert_eq!(bazaar.confidence("HOT_POTATO_BOOK"), 0.0);
+}
+
+#[test]
+fn empty_book() {
+	let mut bazaar = bazaar();
+	let product = bazaar.get_mut("ENCHANTED_DIAMOND").unwrap();
+	product.live_data.sell_summary.clear();
+
+	assert_eq!(bazaar.sell_price("ENCHANTED_DIAMOND"), Some(1200.4f32 as f64));
+}
+
+#[test]
+fn auction_prices() {
+	let index = index();
+
+	assert_eq!(index.buy_price("HOT_POTATO_BOOK"), Some(70_000.0));
+	assert_eq!(index.sell_price("HOT_POTATO_BOOK"), Some(70_000.0));
+	assert_eq!(index.confidence("HOT_POTATO_BOOK"), 0.5);
+	assert_eq!(index.sell_price("SUMMONING_EYE"), None);
+	assert_eq!(index.confidence("SUMMONING_EYE"), 0.0);
+}
+
+#[test]
+fn npc_prices() {
+	let items = items();
+
+	assert_eq!(items.sell_price("STONE"), Some(1.0));
+	assert_eq!(items.buy_price("STONE"), None);
+	assert_eq!(items.confidence("STONE"), 1.0);
+	assert_eq!(items.confidence("VERY_SPECIAL_HAT"), 0.0);
+}
+
+#[test]
+fn composite() {
+	let (bazaar, index, items) = (bazaar(), index(), items());
+	let prices = CompositePrices::new()
+		.then(&bazaar)
+		.then(&index)
+		.then(&items);
+
+	// The bazaar comes first, even though NPCs pay more.
+	assert_eq!(prices.sell_price("ENCHANTED_DIAMOND"), Some(1205.3));
+	assert_eq!(prices.sell_price("HOT_POTATO_BOOK"), Some(70_000.0));
+	assert_eq!(prices.confidence("HOT_POTATO_BOOK"), 0.5);
+	assert_eq!(prices.sell_price("STONE"), Some(1.0));
+	assert_eq!(prices.buy_price("STONE"), None);
+	assert_eq!(prices.confidence("STONE"), 1.0);
+	assert_eq!(prices.sell_price("MYSTERY"), None);
+	assert_eq!(prices.confidence("MYSTERY"), 0.0);
+
+	let confident = CompositePrices::new()
+		.then(&index)
+		.then(&items)
+		.min_confidence(0.6);
+	assert_eq!(confident.sell_price("HOT_POTATO_BOOK"), None);
+	assert_eq!(confident.sell_price("ENCHANTED_DIAMOND"), Some(1280.0));
+}
@@ -0,0 +1,101 @@
+#[cfg(feature = "nbt")]
+use super::profile;
+use crate::objects::*;
+use crate::objects::profile::accessories::*;
+use crate::tests::Prices;
+
+fn bag() -> Vec<Accessory> {
+	vec![
+		Accessory::new("HEGEMONY_ARTIFACT", Rarity::Legendary),
+		Accessory::new("SPEED_TALISMAN", Rarity::Common),
+		Accessory::new("SPEED_RING", Rarity::Uncommon),
+		// Recombobulated
+		Accessory::new("SPEED_RING", Rarity::Rare),
+	]
+}
+
+fn items() -> ItemsResource {
+	serde_json::from_str(include_str!("../deserialize/resources/accessories.json")).unwrap()
+}
+
+#[test]
+fn power() {
+	assert_eq!(magical_power(Rarity::Common), 3);
+	assert_eq!(magical_power(Rarity::Mythic), 22);
+	assert_eq!(magical_power(Rarity::VerySpecial), 5);
+	assert_eq!(Accessory::new("HEGEMONY_ARTIFACT", Rarity::Mythic).magical_power(), 44);
+}
+
+#[test]
+fn analyse() {
+	let analysis = AccessoryAnalysis::analyse(bag(), &UpgradeLines::bundled());
+
+	assert_eq!(analysis.magical_power, 40);
+	assert_eq!(analysis.by_rarity[&Rarity::Legendary], 32);
+	assert_eq!(analysis.by_rarity[&Rarity::Rare], 8);
+	assert_eq!(analysis.counted.len(), 2);
+	assert_eq!(analysis.excluded, vec![
+		ExcludedAccessory {
+			accessory: Accessory::new("SPEED_RING", Rarity::Uncommon),
+			reason: Exclusion::Duplicate,
+		},
+		ExcludedAccessory {
+			accessory: Accessory::new("SPEED_TALISMAN", Rarity::Common),
+			reason: Exclusion::Superseded("SPEED_RING".to_string()),
+		},
+	]);
+}
+
+#[test]
+fn custom_lines() {
+	let mut lines = UpgradeLines::new();
+	lines.push(vec!["SPEED_RING".to_string(), "HEGEMONY_ARTIFACT".to_string()]);
+	let analysis = AccessoryAnalysis::analyse(bag(), &lines);
+
+	// The speed talisman is no longer in a line, so it counts on its own.
+	assert_eq!(analysis.magical_power, 35);
+	assert!(analysis.has("SPEED_RING", &lines));
+}
+
+#[test]
+fn missing() {
+	let (items, lines) = (items(), UpgradeLines::bundled());
+	let analysis = AccessoryAnalysis::analyse(bag(), &lines);
+
+	let missing: Vec<_> = analysis.missing(&items, &lines).iter().map(|item| item.id.as_str()).collect();
+	assert_eq!(missing, vec!["FEATHER_RING", "FEATHER_TALISMAN", "MYSTERY_TALISMAN", "SPEED_ARTIFACT", "WOLF_TALISMAN"]);
+	assert!(analysis.has("SPEED_TALISMAN", &lines));
+	assert!(!analysis.has("SPEED_ARTIFACT", &lines));
+}
+
+#[test]
+fn suggestions() {
+	let (items, lines) = (items(), UpgradeLines::bundled());
+	let analysis = AccessoryAnalysis::analyse(bag(), &lines);
+	let prices = Prices::flat(&[
+		("FEATHER_TALISMAN", 1_000.0),
+		("FEATHER_RING", 5_000.0),
+		("WOLF_TALISMAN", 6_000.0),
+		("SPEED_ARTIFACT", 1_000_000.0),
+	]);
+
+	let suggestions = analysis.suggestions(&items, &lines, &prices);
+	let ids: Vec<_> = suggestions.iter().map(|suggestion| suggestion.id.as_str()).collect();
+
+	// The speed artifact is no better than the recombobulated speed ring.
+	assert_eq!(ids, vec!["FEATHER_TALISMAN", "FEATHER_RING", "WOLF_TALISMAN"]);
+	assert_eq!(suggestions[1].magical_power, 5);
+	assert_eq!(suggestions[1].cost_per_power, 1_000.0);
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn decode() {
+	let profile = profile();
+	let member = profile.member("be366506d9cd415687a9a1c90a888550").unwrap();
+
+	// The feather artifact's lore doesn't carry a rarity.
+	let accessories = member.accessories().unwrap();
+	assert_eq!(accessories, bag());
+	assert_eq!(AccessoryAnalysis::analyse(accessories, &UpgradeLines::bundled()).magical_power, 40);
+}
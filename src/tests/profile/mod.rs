@@ -0,0 +1,21 @@
+use crate::Result;
+use crate::client::ApiBody;
+use crate::objects::Profile;
+
+mod accessories;
+mod networth;
+mod progression;
+mod snapshot;
+
+#[derive(Deserialize)]
+struct ProfileWrapper {
+	profile: Profile,
+}
+
+/// The profile from the deserialize fixtures.
+pub(super) fn profile() -> Profile {
+	let body: ApiBody<ProfileWrapper> = serde_json::from_str(include_str!("../deserialize/profile/profile.json")).unwrap();
+	let res: Result<_> = body.into();
+
+	res.unwrap().profile
+}
\ No newline at end of file
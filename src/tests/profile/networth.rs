@@ -0,0 +1,90 @@
+use super::profile;
+use crate::objects::profile::networth::*;
+use crate::tests::Prices;
+
+fn prices() -> Prices {
+	Prices::flat(&[
+		("HYPERION", 800_000_000.0),
+		("ENCHANTED_DIAMOND", 1200.0),
+		("ENCHANTMENT_SHARPNESS_6", 3_000_000.0),
+		("RECOMBOBULATOR_3000", 6_000_000.0),
+		("HOT_POTATO_BOOK", 80_000.0),
+		("FUMING_POTATO_BOOK", 1_200_000.0),
+		("FIRST_MASTER_STAR", 10_000_000.0),
+		("SECOND_MASTER_STAR", 20_000_000.0),
+		("HEGEMONY_ARTIFACT", 250_000_000.0),
+		("STONE", 1.0),
+		("PET_ENDER_DRAGON_LEGENDARY", 700_000_000.0),
+		("PET_ITEM_TIER_BOOST", 100_000_000.0),
+		("PET_BEE_RARE", 50_000.0),
+	])
+}
+
+fn assert_close(actual: f64, expected: f64) {
+	assert!((actual - expected).abs() < 1e-3, "{} != {}", actual, expected);
+}
+
+#[test]
+fn coins_pets_and_sacks() {
+	let profile = profile();
+	let networth = profile.networth("be366506d9cd415687a9a1c90a888550", &prices(), &Valuation::default()).unwrap().unwrap();
+
+	assert_eq!(networth.category(NetworthCategory::Purse), 1_000_000.5);
+	assert_eq!(networth.category(NetworthCategory::Bank), 25_000_000.0);
+	assert_eq!(networth.category(NetworthCategory::Sacks), 12_000.0);
+
+	let pets = &networth.categories[&NetworthCategory::Pets];
+	assert_close(pets.total, 800_050_000.0);
+	assert_eq!(pets.items[0].id, "PET_ENDER_DRAGON_LEGENDARY");
+	assert_eq!(pets.items[0].upgrades[0].upgrade, Upgrade::HeldItem("PET_ITEM_TIER_BOOST".to_string()));
+
+	assert!(networth.unpriced.contains("MYSTERY"));
+	assert!(networth.unpriced.contains("PET_SKIN_BEE_HONEY"));
+	assert!(!networth.unpriced.contains("WHEAT"));
+}
+
+#[test]
+fn weights() {
+	let profile = profile();
+	let member = profile.member("be366506d9cd415687a9a1c90a888550").unwrap();
+	let valuation = Valuation {
+		pet_items: 0.5,
+		..Valuation::default()
+	};
+
+	let networth = member.networth(&prices(), &valuation).unwrap();
+	assert_close(networth.category(NetworthCategory::Pets), 750_050_000.0);
+	assert_eq!(networth.category(NetworthCategory::Bank), 0.0);
+	assert!(profile.networth("f06e38d0cd634a4ea23306cef8b0bcee", &prices(), &valuation).unwrap().is_ok());
+	assert!(profile.networth("0000", &prices(), &valuation).is_none());
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn items() {
+	let profile = profile();
+	let networth = profile.networth("be366506d9cd415687a9a1c90a888550", &prices(), &Valuation::default()).unwrap().unwrap();
+
+	let inventory = &networth.categories[&NetworthCategory::Inventory];
+	assert_eq!(inventory.items.len(), 2);
+	assert_close(inventory.total, 841_826_800.0);
+
+	let hyperion = &inventory.items[0];
+	assert_eq!(hyperion.id, "HYPERION");
+	let upgrades: Vec<_> = hyperion.upgrades.iter().map(|upgrade| upgrade.upgrade.clone()).collect();
+	assert_eq!(upgrades, vec![
+		Upgrade::Enchantment("sharpness".to_string(), 6),
+		Upgrade::Recombobulator,
+		Upgrade::HotPotatoBooks(10),
+		Upgrade::FumingPotatoBooks(5),
+		Upgrade::MasterStars(2),
+	]);
+	assert_close(hyperion.total, 841_750_000.0);
+
+	assert_eq!(inventory.items[1].count, 64);
+	// The hegemony artifact, and the recombobulator on one of the speed rings.
+	assert_close(networth.category(NetworthCategory::Accessories), 254_800_000.0);
+	assert_eq!(networth.category(NetworthCategory::Backpacks), 32.0);
+	assert!(networth.unpriced.contains("ENCHANTMENT_ULTIMATE_WISE_5"));
+	assert_close(networth.total, 1_922_688_832.5);
+}
@@ -0,0 +1,120 @@
+use super::profile;
+use crate::objects::*;
+use crate::objects::profile::progression::*;
+
+fn member() -> ProfileMember {
+	profile().member("be366506d9cd415687a9a1c90a888550").unwrap().clone()
+}
+
+#[test]
+fn tables() {
+	assert_eq!(XpTable::skill("farming").xp_for_level(50), Some(55_172_425.0));
+	assert_eq!(XpTable::skill("farming").xp_for_level(60), Some(111_672_425.0));
+	assert_eq!(XpTable::skill("fishing").max_level(), 50);
+	assert_eq!(XpTable::skill("runecrafting").xp_for_level(25), Some(94_300.0));
+	assert_eq!(XpTable::catacombs().xp_for_level(50), Some(569_809_640.0));
+	assert_eq!(XpTable::slayer("vampire").unwrap().max_level(), 5);
+	assert!(XpTable::slayer("pigman").is_none());
+	assert_eq!(XpTable::catacombs().xp_for_level(-1), None);
+}
+
+#[test]
+fn skills() {
+	let member = member();
+
+	let farming = member.skill_level("farming").unwrap();
+	assert_eq!(farming.level, 50);
+	assert_eq!(farming.progress, 0.0);
+	assert_eq!(farming.xp_to_next, Some(4_300_000.0));
+
+	let mining = member.skill_level("mining").unwrap();
+	assert!(mining.is_maxed());
+	assert_eq!(mining.xp_to_next, None);
+	assert_eq!(mining.overflow, 38_327_575.0);
+
+	let foraging = member.skill_level("foraging").unwrap();
+	assert_eq!((foraging.level, foraging.overflow), (50, 4_827_575.0));
+
+	let fishing = member.skill_level("fishing").unwrap();
+	assert_eq!(fishing.fractional(), 2.5);
+	assert_eq!(member.skill_level("runecrafting").unwrap().level, 25);
+	assert_eq!(member.skill_level("combat").unwrap().level, 2);
+
+	assert_eq!(member.skill_average(), Some(164.5 / 9.0));
+}
+
+#[test]
+fn untrained_skills() {
+	let mut member = member();
+	member.player_data.experience.remove("SKILL_TAMING");
+	member.player_data.experience.remove("SKILL_CARPENTRY");
+
+	assert!(member.skill_level("taming").is_none());
+	assert_eq!(member.skill_average(), Some(164.5 / 9.0));
+}
+
+#[test]
+fn skill_from_resource() {
+	let skills: SkillsResource = serde_json::from_str(include_str!("../deserialize/resources/skills.json")).unwrap();
+	let table = XpTable::from(&skills.skills["FARMING"]);
+
+	assert_eq!(table.max_level(), 3);
+	assert_eq!(table.level(275.0).fractional(), 2.5);
+	assert_eq!(table.level(400.0).overflow, 25.0);
+}
+
+#[test]
+fn slayers() {
+	let member = member();
+
+	let zombie = member.slayer_level("zombie").unwrap();
+	assert_eq!(zombie.level, 4);
+	assert_eq!(zombie.xp_to_next, Some(3500.0));
+
+	let wolf = member.slayer_level("wolf").unwrap();
+	assert_eq!((wolf.level, wolf.overflow), (9, 500_000.0));
+
+	assert_eq!(member.slayer_level("vampire").unwrap().level, 0);
+	assert_eq!(member.slayer_level("spider").unwrap().xp, 0.0);
+	assert_eq!(member.total_slayer_xp(), 1_501_500.0);
+}
+
+#[test]
+fn spider_slayer() {
+	let mut member = member();
+	member.slayer.slayer_bosses.insert("spider".to_string(), SlayerBoss { xp: 25.0 });
+
+	let spider = member.slayer_level("spider").unwrap();
+	assert_eq!(spider.level, 2);
+	assert_eq!(spider.xp_to_next, Some(175.0));
+
+	member.slayer.slayer_bosses.insert("spider".to_string(), SlayerBoss { xp: 15.0 });
+	let spider = member.slayer_level("spider").unwrap();
+	assert_eq!(spider.level, 1);
+	assert_eq!(spider.progress, 0.5);
+}
+
+#[test]
+fn dungeons() {
+	let member = member();
+
+	assert_eq!(member.catacombs_level().level, 50);
+	assert!(member.catacombs_level().is_maxed());
+
+	let healer = member.class_level("healer");
+	assert_eq!(healer.level, 6);
+	assert_eq!(healer.progress, 45.0 / 470.0);
+	assert_eq!(member.class_level("archer").level, 0);
+}
+
+#[test]
+fn disabled_api() {
+	let profile = profile();
+	let member = profile.member("f06e38d0cd634a4ea23306cef8b0bcee").unwrap();
+
+	assert_eq!(profile.profile_id, ProfileId("5e3e1b2c7a1d4b0f9e2c8d6a4f1b3c5d".to_string()));
+	assert_eq!(profile.cute_name.as_deref(), Some("Pomegranate"));
+	assert!(member.skill_level("farming").is_none());
+	assert_eq!(member.skill_average(), None);
+	assert_eq!(member.catacombs_level().level, 0);
+}
@@ -0,0 +1,89 @@
+use super::profile;
+#[cfg(feature = "nbt")]
+use crate::objects::profile::Container;
+
+const UUID: &str = "be366506d9cd415687a9a1c90a888550";
+
+#[test]
+fn snapshot() {
+	let snapshot = profile().snapshot(UUID, 1_700_000_000_000).unwrap().unwrap();
+
+	assert_eq!(snapshot.timestamp, 1_700_000_000_000);
+	assert_eq!(snapshot.purse, 1_000_000.5);
+	assert_eq!(snapshot.bank, Some(25_000_000.0));
+	assert_eq!(snapshot.skills["mining"], 150_000_000.0);
+	assert_eq!(snapshot.slayers["wolf"], 1_500_000.0);
+	assert_eq!(snapshot.collections["WHEAT"], 120_000);
+
+	#[cfg(feature = "nbt")]
+	{
+		let hyperion = &snapshot.items["3f8d6b2a-1c4e-4b7a-9d2f-6e5a4c3b2a10"];
+		assert_eq!(hyperion.id, "HYPERION");
+		assert_eq!(hyperion.container, Container::Inventory);
+		assert!(snapshot.items.contains_key("a2b4c6d8-0e1f-4a3b-8c5d-7e9f1a2b3c4d"));
+	}
+
+	assert!(profile().snapshot("not a member", 0).is_none());
+}
+
+#[test]
+fn unchanged_diff() {
+	let member = profile().member(UUID).unwrap().clone();
+	let diff = member.snapshot(0).unwrap().diff(&member.snapshot(60_000).unwrap());
+
+	assert!(diff.is_empty());
+	assert_eq!(diff.duration(), 60_000);
+}
+
+#[test]
+fn diff() {
+	let mut member = profile().member(UUID).unwrap().clone();
+	let before = member.snapshot(0).unwrap();
+
+	member.currencies.coin_purse -= 250_000.0;
+	*member.player_data.experience.get_mut("SKILL_FARMING").unwrap() += 12_000.0;
+	*member.player_data.experience.get_mut("SKILL_COMBAT").unwrap() += 500.0;
+	*member.collection.get_mut("WHEAT").unwrap() += 3_000;
+	member.collection.insert("CARROT_ITEM".to_owned(), 640);
+	member.slayer.slayer_bosses.get_mut("zombie").unwrap().xp += 100.0;
+
+	let diff = before.diff(&member.snapshot(1_000).unwrap());
+
+	assert!(!diff.is_empty());
+	assert_eq!(diff.purse, -250_000.0);
+	assert_eq!(diff.bank, None);
+	assert_eq!(diff.skill_xp.len(), 2);
+	assert_eq!(diff.skill_xp["farming"], 12_000.0);
+	assert_eq!(diff.total_skill_xp(), 12_500.0);
+	assert_eq!(diff.slayer_xp["zombie"], 100.0);
+	assert_eq!(diff.collections.len(), 2);
+	assert_eq!(diff.collections["WHEAT"], 3_000);
+	assert_eq!(diff.collections["CARROT_ITEM"], 640);
+	assert!(diff.items_added.is_empty());
+	assert!(diff.items_removed.is_empty());
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn item_diff() {
+	let mut member = profile().member(UUID).unwrap().clone();
+	let before = member.snapshot(0).unwrap();
+
+	member.inventory.ender_chest_contents = member.inventory.inv_contents.take();
+	let moved = before.diff(&member.snapshot(1_000).unwrap());
+
+	assert!(moved.items_added.is_empty());
+	assert!(moved.items_removed.is_empty());
+	assert!(moved.items_moved.iter().any(|moved| moved.item.id == "HYPERION"
+		&& moved.from == Container::Inventory
+		&& moved.item.container == Container::EnderChest));
+
+	member.inventory.ender_chest_contents = None;
+	let removed = before.diff(&member.snapshot(2_000).unwrap());
+
+	assert!(removed.items_removed.iter().any(|item| item.uuid == "3f8d6b2a-1c4e-4b7a-9d2f-6e5a4c3b2a10"));
+	assert!(removed.items_moved.is_empty());
+
+	let added = member.snapshot(2_000).unwrap().diff(&before);
+	assert_eq!(added.items_added, removed.items_removed);
+}
@@ -0,0 +1,128 @@
+use crate::objects::*;
+use crate::objects::recipes::craft::*;
+use crate::tests::Prices;
+
+fn prices() -> Prices {
+	Prices::new(&[
+		("DIAMOND", (7.0, 6.5)),
+		("ENCHANTED_DIAMOND", (1250.0, 1205.0)),
+		("ENCHANTED_DIAMOND_BLOCK", (210_000.0, 200_000.0)),
+		("COAL", (3.0, 2.0)),
+		("ENCHANTED_COAL", (400.0, 300.0)),
+	])
+}
+
+#[test]
+fn bundled() {
+	let recipes = Recipes::bundled();
+	let recipe = recipes.get("ENCHANTED_DIAMOND_BLOCK").unwrap();
+
+	assert_eq!(recipe.count, 1);
+	assert_eq!(recipe.ingredients["ENCHANTED_DIAMOND"], 160);
+	assert_eq!(recipes.get("ENCHANTED_STRING").unwrap().ingredients["STRING"], 192);
+	assert_eq!(recipes.get("ENCHANTED_GLOWSTONE_DUST").unwrap().ingredients["GLOWSTONE_DUST"], 192);
+	assert_eq!(recipes.get("ENCHANTED_QUARTZ_BLOCK").unwrap().ingredients["ENCHANTED_QUARTZ"], 4);
+	assert_eq!(recipes.get("ENCHANTED_BREAD").unwrap().ingredients["WHEAT"], 60);
+	assert_eq!(recipes.get("ENCHANTED_ENDER_PEARL").unwrap().ingredients["ENDER_PEARL"], 20);
+	assert!(recipes.get("DIAMOND").is_none());
+}
+
+#[test]
+fn load() {
+	let mut recipes = Recipes::bundled();
+	let loaded: Recipes = serde_json::from_str(r#"{
+		"SUPER_COMPACTOR_3000": {
+			"ingredients": { "ENCHANTED_REDSTONE_BLOCK": 1, "ENCHANTED_COBBLESTONE": 448 }
+		},
+		"ENCHANTED_DIAMOND": {
+			"count": 2,
+			"ingredients": { "DIAMOND": 320 }
+		}
+	}"#).unwrap();
+	let len = recipes.len();
+	recipes.extend(loaded);
+
+	assert_eq!(recipes.len(), len + 1);
+	assert_eq!(recipes.get("SUPER_COMPACTOR_3000").unwrap().count, 1);
+	assert_eq!(recipes.get("ENCHANTED_DIAMOND").unwrap().count, 2);
+}
+
+#[test]
+fn empty_recipes() {
+	let loaded: Result<Recipes, _> = serde_json::from_str(r#"{
+		"ENCHANTED_DIAMOND": { "count": 0, "ingredients": { "DIAMOND": 160 } }
+	}"#);
+	assert!(loaded.is_err());
+
+	let mut recipes = Recipes::new();
+	let recipe = Recipe {
+		count: -1,
+		ingredients: vec![("DIAMOND".to_string(), 160)].into_iter().collect(),
+	};
+	assert!(recipes.insert("ENCHANTED_DIAMOND", recipe).is_err());
+	assert!(recipes.is_empty());
+}
+
+#[test]
+fn recursive() {
+	let (recipes, prices) = (Recipes::bundled(), prices());
+	let calculator = CraftCalculator::new(&recipes, &prices);
+
+	// Crafting enchanted diamonds at 1120 beats buying them at 1250.
+	let plan = calculator.craft("ENCHANTED_DIAMOND_BLOCK").unwrap();
+	assert_eq!(plan.unit_cost, 179_200.0);
+	assert_eq!(plan.bought("DIAMOND"), 25_600.0);
+	assert_eq!(plan.bought("ENCHANTED_DIAMOND"), 0.0);
+
+	// Buying enchanted coal at 400 beats crafting it at 480.
+	let plan = calculator.craft("ENCHANTED_COAL_BLOCK").unwrap();
+	assert_eq!(plan.unit_cost, 64_000.0);
+	assert_eq!(plan.bought("ENCHANTED_COAL"), 160.0);
+
+	assert_eq!(calculator.plan("DIAMOND").unwrap().method, Method::Buy);
+	assert!(calculator.plan("ENCHANTED_STRING").is_none());
+	assert!(calculator.craft("DIAMOND").is_none());
+}
+
+#[test]
+fn cycles() {
+	let mut recipes = Recipes::bundled();
+	recipes.insert("DIAMOND", Recipe {
+		count: 160,
+		ingredients: vec![("ENCHANTED_DIAMOND".to_string(), 1)].into_iter().collect(),
+	}).unwrap();
+	let prices = prices();
+	let calculator = CraftCalculator::new(&recipes, &prices);
+
+	assert_eq!(calculator.plan("ENCHANTED_DIAMOND").unwrap().unit_cost, 1120.0);
+	assert_eq!(calculator.plan("DIAMOND").unwrap().unit_cost, 7.0);
+}
+
+#[test]
+fn flips() {
+	let (recipes, prices) = (Recipes::bundled(), prices());
+	let calculator = CraftCalculator::new(&recipes, &prices);
+
+	let flips = calculator.flips();
+	let ids: Vec<_> = flips.iter().map(|flip| flip.id.as_str()).collect();
+	assert_eq!(ids, vec!["ENCHANTED_DIAMOND_BLOCK", "ENCHANTED_DIAMOND"]);
+	assert_eq!(flips[0].profit, 20_800.0);
+	assert_eq!(flips[1].profit, 85.0);
+
+	// Crafting enchanted coal costs more than it sells for.
+	assert_eq!(calculator.flip("ENCHANTED_COAL").unwrap().profit, -180.0);
+}
+
+#[test]
+fn free_crafts() {
+	let recipes = Recipes::bundled();
+	let prices = Prices::new(&[
+		("COAL", (0.0, 0.0)),
+		("ENCHANTED_COAL", (400.0, 300.0)),
+	]);
+	let calculator = CraftCalculator::new(&recipes, &prices);
+
+	assert_eq!(calculator.craft("ENCHANTED_COAL").unwrap().unit_cost, 0.0);
+	assert!(calculator.flip("ENCHANTED_COAL").is_none());
+	assert!(calculator.flips().is_empty());
+}